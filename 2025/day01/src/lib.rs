@@ -0,0 +1,125 @@
+//! A safe dial numbered 0 to 99, turned left and right by a list of
+//! rotations, and how often it points at 0.
+
+use std::fmt::Display;
+
+use aoc_core::{parse, ParseError, Rng, Solution, SolveError};
+
+/// The dial, with counts of the times it has pointed at 0.
+#[derive(Debug, Clone)]
+pub struct Dial {
+    position: u32,
+    zero_count_p1: u64,
+    zero_count_p2: u64,
+}
+
+impl Default for Dial {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Dial {
+    /// A dial pointing at 50, as it starts in the puzzle.
+    pub fn new() -> Self {
+        Dial { position: 50, zero_count_p1: 0, zero_count_p2: 0 }
+    }
+
+    pub fn position(&self) -> u32 {
+        self.position
+    }
+
+    /// How many rotations have ended at 0 (part 1).
+    pub fn stops_at_zero(&self) -> u64 {
+        self.zero_count_p1
+    }
+
+    /// How many clicks have landed on 0, during rotations or at their end
+    /// (part 2).
+    pub fn clicks_at_zero(&self) -> u64 {
+        self.zero_count_p2
+    }
+
+    /// Applies one rotation like `L68` or `R30`.
+    pub fn ingest_instruction(&mut self, instruction: &str) -> Result<(), ParseError> {
+        let direction = instruction.get(0..1)
+            .ok_or_else(|| ParseError::new(instruction, instruction, "`L` or `R`"))?;
+        let steps: u32 = parse::number(instruction, instruction[1..].trim(), "a number of steps")?;
+
+        match direction {
+            "R" => self.rotate_right(steps),
+            "L" => self.rotate_left(steps),
+            _ => return Err(ParseError::new(instruction, direction, "`L` or `R`")),
+        }
+
+        Ok(())
+    }
+
+    pub fn rotate_right(&mut self, steps: u32) {
+        // Counted in 64 bits, so no number of steps overflows
+        let end = self.position as u64 + steps as u64;
+        self.zero_count_p2 += end / 100;
+
+        self.position = (end % 100) as u32;
+        if self.position == 0 {
+            self.zero_count_p1 += 1;
+        }
+    }
+
+    pub fn rotate_left(&mut self, steps: u32) {
+        self.zero_count_p2 += (steps as u64 + 100 - self.position as u64) / 100;
+        
+        if self.position == 0 {
+            self.zero_count_p2 -= 1;
+        }
+        
+        self.position = (self.position + 100 - (steps % 100)) % 100;
+        if self.position == 0 {
+            self.zero_count_p1 += 1;
+        }
+    }
+}
+
+/// A dial that has made every rotation in the input, one per line.
+impl std::str::FromStr for Dial {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let mut dial = Dial::new();
+
+        for line in input.lines() {
+            dial.ingest_instruction(line)
+                .map_err(|err| err.rebase(input, line))?;
+        }
+
+        Ok(dial)
+    }
+}
+
+pub struct Day01;
+
+impl Solution for Day01 {
+    const DAY: u8 = 1;
+
+    type Input = Dial;
+
+    fn parse(input: &str) -> Result<Dial, ParseError> {
+        input.parse()
+    }
+
+    fn part1(dial: &Dial) -> Result<impl Display, SolveError> {
+        Ok(dial.stops_at_zero())
+    }
+
+    fn part2(dial: &Dial) -> Result<impl Display, SolveError> {
+        Ok(dial.clicks_at_zero())
+    }
+
+    /// `size` rotations of up to 999 steps.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let lines = (0..size)
+            .map(|_| format!("{}{}", rng.pick(&["L", "R"]), rng.range(1..=999)))
+            .collect::<Vec<_>>();
+        Some(lines.join("\n") + "\n")
+    }
+}
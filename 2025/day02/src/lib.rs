@@ -0,0 +1,248 @@
+//! Ranges of product IDs, and the invalid IDs in them: those made of a run
+//! of digits repeated.
+
+use std::fmt::Display;
+
+use aoc_core::{parallel, parse, ParseError, Rng, Solution, SolveError};
+
+type TDigit = u64;
+
+/// An inclusive range of product IDs, like `11-22`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Range {
+    pub start: TDigit,
+    pub end: TDigit,
+}
+
+/// The most halves or segments [`Range::find_invalid_ids_p1`] and
+/// [`Range::find_invalid_ids_p2`] try before giving up on listing a range.
+pub const LIST_LIMIT: u64 = 1_000_000;
+
+/// How many values lie in all the inclusive `(first, last)` spans together.
+fn tries(spans: impl Iterator<Item = (TDigit, TDigit)>) -> u64 {
+    spans.map(|(first, last)| if first <= last { last - first + 1 } else { 0 }).sum()
+}
+
+fn pow10(exp: TDigit) -> TDigit {
+    10u64.pow(exp.try_into().unwrap())
+}
+
+impl Range {
+    pub fn new(start: TDigit, end: TDigit) -> Self {
+        Self { start, end }
+    }
+
+    /// The IDs in the range made of some digits repeated exactly twice, like
+    /// `6464` (part 1), or `None` if that means trying more than
+    /// [`LIST_LIMIT`] halves; [`Range::sum_invalid_ids_p1`] copes with any
+    /// range.
+    pub fn find_invalid_ids_p1(&self) -> Option<Vec<TDigit>> {
+        // The first and last half to try for each even number of digits
+        let halves = self.digit_counts()
+            .filter(|digits| digits % 2 == 0)
+            .map(|digit_count| {
+                let segment_length = digit_count / 2;
+
+                let lower_bound = pow10(segment_length as TDigit - 1);
+                let upper_bound = pow10(segment_length as TDigit) - 1;
+
+                let start_top = self.start / pow10(segment_length as TDigit);
+                let end_top = self.end / pow10(segment_length as TDigit);
+
+                (digit_count, lower_bound.max(start_top), upper_bound.min(end_top))
+            })
+            .collect::<Vec<_>>();
+        if tries(halves.iter().map(|&(_, first, last)| (first, last))) > LIST_LIMIT {
+            return None;
+        }
+
+        let mut invalid_ids : Vec<TDigit> = vec![];
+        for (digit_count, range_start, range_end) in halves {
+            for half in range_start..=range_end {
+                let bottom = half;
+                let top = half * pow10(digit_count as TDigit / 2);
+                let id = top + bottom;
+                if id < self.start || id > self.end {
+                    continue;
+                }
+
+                invalid_ids.push(id);
+            }
+        }
+        Some(invalid_ids)
+    }
+
+    /// The sum of [`Range::find_invalid_ids_p1`], without listing them.
+    pub fn sum_invalid_ids_p1(&self) -> u128 {
+        self.digit_counts()
+            .filter(|digits| digits % 2 == 0)
+            .map(|digits| self.sum_repeats(digits / 2, 2))
+            .sum()
+    }
+
+    /// The IDs in the range made of some digits repeated at least twice,
+    /// like `646464` (part 2), each listed once, or `None` if that means
+    /// trying more than [`LIST_LIMIT`] segments;
+    /// [`Range::sum_invalid_ids_p2`] copes with any range.
+    pub fn find_invalid_ids_p2(&self) -> Option<Vec<TDigit>> {
+        // The first and last segment to try for each number of digits and
+        // of repeats
+        let mut segments = vec![];
+        for digit_count in self.digit_counts() {
+            for divisor in 2..=digit_count {
+                if digit_count % divisor != 0 {
+                    continue;
+                }
+
+                let segment_length = digit_count / divisor;
+                let lower_bound = pow10(segment_length as TDigit - 1);
+                let upper_bound = pow10(segment_length as TDigit) - 1;
+
+                let start_segment = self.start / pow10(digit_count as TDigit - segment_length as TDigit);
+                let end_segment = self.end / pow10(digit_count as TDigit - segment_length as TDigit);
+
+                segments.push((segment_length, divisor, lower_bound.max(start_segment), upper_bound.min(end_segment)));
+            }
+        }
+        if tries(segments.iter().map(|&(_, _, first, last)| (first, last))) > LIST_LIMIT {
+            return None;
+        }
+
+        let mut invalid_ids : Vec<TDigit> = vec![];
+        for (segment_length, divisor, range_start, range_end) in segments {
+            for segment in range_start..=range_end {
+                // An ID made of a shorter segment repeated, like `1111`
+                // from `11`, is found again with that segment; only that
+                // one counts, so each ID is found once.
+                if is_repeated(segment, segment_length) {
+                    continue;
+                }
+
+                let id = repeat(segment, segment_length, divisor);
+                if id < self.start || id > self.end {
+                    continue;
+                }
+
+                invalid_ids.push(id);
+            }
+        }
+        Some(invalid_ids)
+    }
+
+    /// The sum of [`Range::find_invalid_ids_p2`], without listing them.
+    pub fn sum_invalid_ids_p2(&self) -> u128 {
+        self.digit_counts().map(|digits| self.sum_periodic(digits)).sum()
+    }
+
+    /// How many digits the IDs in the range can have.
+    fn digit_counts(&self) -> std::ops::RangeInclusive<usize> {
+        self.start.to_string().len()..=self.end.to_string().len()
+    }
+
+    /// The sum of the IDs in the range made of a `length`-digit segment
+    /// written `times` times: every segment between two bounds, times the
+    /// same multiplier, like `1001` for two three-digit halves.
+    fn sum_repeats(&self, length: usize, times: usize) -> u128 {
+        let multiplier = repeat(1, length, times) as u128;
+        let lowest = (pow10(length as TDigit - 1) as u128).max((self.start as u128).div_ceil(multiplier));
+        let highest = (pow10(length as TDigit) as u128 - 1).min(self.end as u128 / multiplier);
+
+        if lowest > highest {
+            return 0;
+        }
+        multiplier * (lowest + highest) * (highest - lowest + 1) / 2
+    }
+
+    /// The sum of the `digits`-digit IDs in the range made of some segment
+    /// written at least twice. Those repeating a segment of `length` digits
+    /// also repeat any segment a multiple of that, so every such ID repeats
+    /// a segment `digits / p` long for some prime `p`; inclusion-exclusion
+    /// over those primes counts each ID once.
+    fn sum_periodic(&self, digits: usize) -> u128 {
+        let primes = (2..=digits)
+            .filter(|&p| digits.is_multiple_of(p) && (2..p).all(|q| !p.is_multiple_of(q)))
+            .collect::<Vec<_>>();
+
+        let mut sum: i128 = 0;
+        for subset in 1..1u32 << primes.len() {
+            let product = (0..primes.len())
+                .filter(|i| subset & (1 << i) != 0)
+                .map(|i| primes[i])
+                .product::<usize>();
+            let repeats = self.sum_repeats(digits / product, product) as i128;
+
+            sum += if subset.count_ones() % 2 == 1 { repeats } else { -repeats };
+        }
+        sum as u128
+    }
+}
+
+/// `segment`, `length` digits long, written `times` times in a row.
+fn repeat(segment: TDigit, length: usize, times: usize) -> TDigit {
+    let mut id: TDigit = 0;
+    for i in 0..times {
+        id += segment * pow10(length as TDigit * (times as TDigit - i as TDigit - 1));
+    }
+    id
+}
+
+/// Whether `segment`, `length` digits long, is itself a shorter run of
+/// digits repeated.
+fn is_repeated(segment: TDigit, length: usize) -> bool {
+    (1..length)
+        .filter(|part| length.is_multiple_of(*part))
+        .any(|part| repeat(segment / pow10((length - part) as TDigit), part, length / part) == segment)
+}
+
+impl std::str::FromStr for Range {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let trimmed = s.trim();
+        let (start, end) = trimmed.split_once('-')
+            .ok_or_else(|| ParseError::new(s, trimmed, "a range like `11-22`"))?;
+
+        let start = parse::number(s, start, "a number")?;
+        let end = parse::number(s, end, "a number")?;
+        Ok(Range { start, end })
+    }
+}
+
+pub struct Day02;
+
+impl Solution for Day02 {
+    const DAY: u8 = 2;
+
+    type Input = Vec<Range>;
+
+    fn parse(input: &str) -> Result<Vec<Range>, ParseError> {
+        input
+            .split(",")
+            .map(|part| part.parse().map_err(|err: ParseError| err.rebase(input, part)))
+            .collect()
+    }
+
+    // A range's invalid IDs add up to well within 128 bits, even for a
+    // range over every 64-bit ID.
+    fn part1(ranges: &Vec<Range>) -> Result<impl Display, SolveError> {
+        Ok(parallel::sum(ranges, Range::sum_invalid_ids_p1))
+    }
+
+    fn part2(ranges: &Vec<Range>) -> Result<impl Display, SolveError> {
+        Ok(parallel::sum(ranges, Range::sum_invalid_ids_p2))
+    }
+
+    /// `size` ranges of up to ten-digit IDs, each spanning at most a million
+    /// IDs.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let ranges = (0..size)
+            .map(|_| {
+                let digits = rng.range(1..=10) as u32;
+                let start = rng.range(10u64.pow(digits - 1)..=10u64.pow(digits) - 1);
+                let end = start + rng.range(0..=10u64.pow(digits.min(6)));
+                format!("{}-{}", start, end)
+            })
+            .collect::<Vec<_>>();
+        Some(ranges.join(",") + "\n")
+    }
+} 
\ No newline at end of file
@@ -0,0 +1,168 @@
+//! Banks of batteries rated 1 to 9, and the largest joltage each bank can
+//! make by turning on some of its batteries in order.
+
+use std::fmt::Display;
+
+use aoc_core::{command, parallel, parse, Command, ParseError, Rng, Solution, SolveError, Table};
+
+/// One bank, like `987654321111111`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BatteryBank {
+    cells: Vec<u64>,
+}
+
+impl BatteryBank {
+    /// The batteries' ratings, in order.
+    pub fn cells(&self) -> &[u64] {
+        &self.cells
+    }
+
+    /// The largest joltage from two batteries (part 1). The bank must have
+    /// at least two.
+    pub fn best2(&self) -> u64 {
+        let best_first = self.cells[..self.cells.len()-1]
+            .iter()
+            .enumerate()
+            // Choose the larger number, and in case of a tie, the one with the lower index
+            .max_by(|(i1, num1), (i2, num2)| {
+                if num1 == num2 {
+                    i2.cmp(i1)
+                } else {
+                    num1.cmp(num2)
+                }
+            })
+            .map(|(i, _num)| i)
+            .unwrap();
+
+        let best_second = self.cells[best_first + 1..]
+            .iter()
+            .enumerate()
+            .max_by_key(|(_i, num)| **num)
+            .map(|(i, _num)| i + best_first + 1)
+            .unwrap();
+
+        self.cells[best_first] * 10 + self.cells[best_second]
+    }
+
+    /// The largest joltage from twelve batteries (part 2). The bank must
+    /// have at least twelve.
+    pub fn best12(&self) -> u64 {
+        let mut indices = vec![];
+        let mut last_allowed_index = 0;
+
+        for place in 0..12 {
+            let best = self.cells[last_allowed_index..self.cells.len() - (11 - place)]
+                .iter()
+                .enumerate()
+                .max_by(|(i1, num1), (i2, num2)| {
+                    if num1 == num2 {
+                        i2.cmp(i1)
+                    } else {
+                        num1.cmp(num2)
+                    }
+                })
+                .map(|(i, _num)| i + last_allowed_index)
+                .unwrap();
+
+            indices.push(best);
+            last_allowed_index = best + 1;
+        }
+
+        let mut result = 0;
+        for index in indices {
+            result = result * 10 + self.cells[index];
+        }
+        result
+    }
+}
+
+impl std::str::FromStr for BatteryBank {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let cells = s
+            .char_indices()
+            .map(|(i, char)| {
+                char.to_digit(10)
+                    .map(|digit| digit as u64)
+                    .ok_or_else(|| ParseError::new(s, &s[i..i + char.len_utf8()], "a digit"))
+            })
+            .collect::<Result<_, _>>()?;
+
+        Ok(BatteryBank { cells })
+    }
+}
+
+impl std::fmt::Display for BatteryBank {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for cell in &self.cells {
+            write!(f, "{}", cell)?;
+        }
+        Ok(())
+    }
+}
+
+/// Makes sure every bank has at least `count` batteries to turn on.
+fn require_batteries(banks: &[BatteryBank], count: usize) -> Result<(), SolveError> {
+    match banks.iter().position(|bank| bank.cells.len() < count) {
+        Some(i) => Err(SolveError::new(format!("bank {} has fewer than {} batteries", i + 1, count))),
+        None => Ok(()),
+    }
+}
+
+/// A row for `bank`, leaving out joltages it has too few batteries for.
+fn bank_table(label: String, bank: &BatteryBank) -> Table {
+    let best = |count, best: fn(&BatteryBank) -> u64| {
+        if bank.cells.len() < count { "-".to_string() } else { best(bank).to_string() }
+    };
+
+    Table::new(&["Bank", "Batteries", "Best of 2", "Best of 12"])
+        .row([label, bank.to_string(), best(2, BatteryBank::best2), best(12, BatteryBank::best12)])
+}
+
+fn bank(banks: &[BatteryBank], args: &[&str]) -> Result<Table, String> {
+    let i = command::position(args, 0, "a bank number", banks.len())?;
+    Ok(bank_table((i + 1).to_string(), &banks[i]))
+}
+
+fn best(_banks: &[BatteryBank], args: &[&str]) -> Result<Table, String> {
+    let bank: BatteryBank = command::arg(args, 0, "batteries like `818181911112111`")?;
+    Ok(bank_table("-".to_string(), &bank))
+}
+
+pub struct Day03;
+
+impl Solution for Day03 {
+    const DAY: u8 = 3;
+
+    type Input = Vec<BatteryBank>;
+
+    fn parse(input: &str) -> Result<Vec<BatteryBank>, ParseError> {
+        parse::lines(input)
+    }
+
+    fn part1(banks: &Vec<BatteryBank>) -> Result<impl Display, SolveError> {
+        require_batteries(banks, 2)?;
+        Ok(parallel::sum(banks, BatteryBank::best2))
+    }
+
+    fn part2(banks: &Vec<BatteryBank>) -> Result<impl Display, SolveError> {
+        require_batteries(banks, 12)?;
+        Ok(parallel::sum(banks, BatteryBank::best12))
+    }
+
+    /// `size` banks of 100 batteries rated 1 to 9.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let banks = (0..size)
+            .map(|_| (0..100).map(|_| char::from(b'0' + rng.range(1..=9) as u8)).collect::<String>())
+            .collect::<Vec<_>>();
+        Some(banks.join("\n") + "\n")
+    }
+
+    fn commands() -> Vec<Command<Vec<BatteryBank>>> {
+        vec![
+            Command::new("bank", "<n>", "The best joltages of the input's nth bank", bank),
+            Command::new("best", "<batteries>", "The best joltages of a bank typed in", best),
+        ]
+    }
+}
@@ -0,0 +1,160 @@
+//! A floor of paper rolls, and the rolls a forklift can reach: those with
+//! fewer than four rolls around them.
+
+use std::fmt::Display;
+
+use aoc_core::{Color, Frame, Glyph, Grid, ParseError, Rng, Scene, Solution, SolveError, Style, Vec2};
+
+/// The floor, with `true` wherever a roll stands.
+#[derive(Debug, Clone)]
+pub struct Floor {
+    rolls: Grid<bool>
+}
+
+impl Floor {
+    pub fn new(rolls: Grid<bool>) -> Self {
+        Floor { rolls }
+    }
+
+    pub fn rolls(&self) -> &Grid<bool> {
+        &self.rolls
+    }
+
+    /// How many rolls a forklift can reach right away (part 1).
+    pub fn count_forkliftable(&self) -> usize {
+        self.rolls.positions()
+            .filter(|&pos| self.is_forkliftable(pos))
+            .count()
+    }
+
+    /// Whether there is a roll at `pos` with fewer than four neighbours.
+    pub fn is_forkliftable(&self, pos: Vec2<usize>) -> bool {
+        if !self.rolls[pos] {
+            return false;
+        }
+
+        self.rolls.neighbors8(pos)
+            .filter(|&neighbor| self.rolls[neighbor])
+            .count() < 4
+    }
+
+    /// The rolls, with the ones a forklift can reach right away highlighted.
+    fn scene(&self) -> Scene {
+        let colors = self.rolls.map(|pos, &roll| {
+            roll.then(|| if self.is_forkliftable(pos) { "orange" } else { "gray" })
+        });
+
+        let mut scene = Scene::new();
+        scene.set_title(&format!("{} rolls accessible", self.count_forkliftable()));
+        scene.grid(&colors, |&color| color);
+        scene.legend(Style::fill("gray").label("roll"));
+        scene.legend(Style::fill("orange").label("accessible roll"));
+        scene
+    }
+
+    /// One frame per round of removals, marking the rolls each round takes.
+    /// Rolls are taken a whole round at a time here, which ends on the same
+    /// floor as removing them one by one.
+    fn removal_frames(&self) -> Vec<Frame> {
+        let mut floor = self.clone();
+        let mut frames = vec![];
+        let mut total = 0;
+
+        for round in 1.. {
+            let taken = floor.rolls.map(|pos, _| floor.is_forkliftable(pos));
+            let count = taken.iter().filter(|(_, &taken)| taken).count();
+
+            let caption = match count {
+                0 => format!("round {}: nothing left to take, {} rolls taken", round, total),
+                _ => format!("round {}: taking {} rolls, {} so far", round, count, total + count),
+            };
+            frames.push(Frame::from_grid(caption, &floor.rolls, |pos, &roll| match (roll, taken[pos]) {
+                (true, true) => Glyph::new('x', Color::Red),
+                (true, false) => Glyph::new('@', Color::Gray),
+                (false, _) => Glyph::new('.', Color::Default),
+            }));
+
+            if count == 0 {
+                break;
+            }
+            for (pos, _) in taken.iter().filter(|(_, &taken)| taken) {
+                floor.rolls[pos] = false;
+            }
+            total += count;
+        }
+
+        frames
+    }
+
+    /// Removes rolls until none can be reached, and returns how many were
+    /// removed (part 2).
+    pub fn remove_forkliftables(&mut self) -> usize {
+        let mut changed = true;
+        let mut removed = 0;
+
+        while changed {
+            changed = false;
+
+            for pos in self.rolls.positions() {
+                if self.is_forkliftable(pos) {
+                    self.rolls[pos] = false;
+                    changed = true;
+                    removed += 1;
+                }
+            }
+        }
+        
+        removed
+    }
+}
+
+impl std::str::FromStr for Floor {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let rolls = Grid::parse(input, "`@` or `.`", |char| match char {
+            '@' => Some(true),
+            '.' => Some(false),
+            _ => None,
+        })?;
+
+        Ok(Floor { rolls })
+    }
+}
+
+pub struct Day04;
+
+impl Solution for Day04 {
+    const DAY: u8 = 4;
+
+    type Input = Floor;
+
+    fn parse(input: &str) -> Result<Floor, ParseError> {
+        input.parse()
+    }
+
+    fn part1(floor: &Floor) -> Result<impl Display, SolveError> {
+        Ok(floor.count_forkliftable())
+    }
+
+    fn part2(floor: &Floor) -> Result<impl Display, SolveError> {
+        Ok(floor.clone().remove_forkliftables())
+    }
+
+    fn render(floor: &Floor) -> Option<Scene> {
+        Some(floor.scene())
+    }
+
+    fn animate(floor: &Floor) -> Option<Vec<Frame>> {
+        Some(floor.removal_frames())
+    }
+
+    /// A `size` by `size` floor, a bit over half of it covered in rolls.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let rows = (0..size)
+            .map(|_| (0..size).map(|_| if rng.chance(0.6) { '@' } else { '.' }).collect::<String>())
+            .collect::<Vec<_>>();
+        Some(rows.join("\n") + "\n")
+    }
+}
+
@@ -0,0 +1,196 @@
+//! Ranges of fresh ingredient IDs, and which ingredients are fresh.
+
+use std::fmt::Display;
+
+use aoc_core::{command, parse, Command, ParseError, Rng, Solution, SolveError, Table};
+
+/// Inclusive ranges of fresh IDs, then the IDs of the ingredients on hand,
+/// separated by a blank line.
+#[derive(Debug, Clone)]
+pub struct IngredientDatabase {
+    fresh_ranges : Vec<(u64, u64)>,
+    ingredients : Vec<u64>
+}
+
+impl std::str::FromStr for IngredientDatabase {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, ParseError> {
+        let mut lines = value.lines();
+
+        // Fresh ranges come first, separated from the ingredients by a blank line
+        let mut fresh_ranges = vec![];
+        for line in lines.by_ref() {
+            if line.trim().is_empty() {
+                break;
+            }
+
+            let (start_text, end_text) = line.split_once('-')
+                .ok_or_else(|| ParseError::new(value, line, "a range like `3-5`"))?;
+            let start: u64 = parse::number(value, start_text.trim(), "a range start")?;
+            let end: u64 = parse::number(value, end_text.trim(), "a range end")?;
+            if end < start {
+                return Err(ParseError::new(value, end_text.trim(), format!("a range end of at least {}", start)));
+            }
+            fresh_ranges.push((start, end));
+        }
+
+        let ingredients = lines
+            .map(|line| parse::number(value, line.trim(), "an ingredient ID"))
+            .collect::<Result<_, _>>()?;
+
+        Ok(Self {
+            fresh_ranges,
+            ingredients,
+        })
+    }
+}
+
+impl IngredientDatabase {
+    pub fn new(fresh_ranges: Vec<(u64, u64)>, ingredients: Vec<u64>) -> Self {
+        Self { fresh_ranges, ingredients }
+    }
+
+    /// The fresh ranges as `(start, end)`, both inclusive.
+    pub fn fresh_ranges(&self) -> &[(u64, u64)] {
+        &self.fresh_ranges
+    }
+
+    pub fn ingredients(&self) -> &[u64] {
+        &self.ingredients
+    }
+
+    pub fn is_fresh(&self, ingredient: u64) -> bool {
+        self.fresh_range(ingredient).is_some()
+    }
+
+    /// The first fresh range that holds `ingredient`, if any does.
+    pub fn fresh_range(&self, ingredient: u64) -> Option<(u64, u64)> {
+        self.fresh_ranges.iter()
+            .copied()
+            .find(|&(start, end)| ingredient >= start && ingredient <= end)
+    }
+
+    /// How many ingredients on hand are fresh (part 1).
+    pub fn count_fresh_ingredients(&self) -> usize {
+        self.ingredients.iter()
+            .filter(|&&ing| self.is_fresh(ing))
+            .count()
+    }
+
+    /// How many IDs the fresh ranges cover between them (part 2). Counted in
+    /// 128 bits, since ranges can cover every 64-bit ID. Each range must end
+    /// no earlier than it starts.
+    pub fn count_all_fresh_ingredients(&self) -> u128 {
+        let mut fresh_ranges : Vec<(u64, u64)> = vec![];
+
+        for &(mut start, mut end) in &self.fresh_ranges {
+            let mut insert = true;
+            
+            let mut i = 0;
+            while i < fresh_ranges.len() {
+                let (other_start, other_end) = fresh_ranges[i];
+
+                if other_start <= start && end <= other_end {
+                    // Current range is fully covered
+                    insert = false;
+                    break;
+                } else if start <= other_start && other_end <= end {
+                    // Current range fully covers existing range
+                    fresh_ranges.remove(i);
+                } else if start <= other_end && other_start <= end {
+                    // Ranges overlap, merge them
+                    start = other_start.min(start);
+                    end = other_end.max(end);
+                    fresh_ranges.remove(i);
+                } else {
+                    i += 1;
+                }
+            }
+
+            if insert {
+                fresh_ranges.push((start, end));
+            }
+        }
+
+        fresh_ranges.iter()
+            .map(|&(start, end)| (end - start) as u128 + 1)
+            .sum()
+    }
+}
+
+fn fresh(db: &IngredientDatabase, args: &[&str]) -> Result<Table, String> {
+    if args.is_empty() {
+        return Err("expected an ingredient ID".to_string());
+    }
+
+    let mut table = Table::new(&["ID", "Fresh", "Range"]);
+    for word in args {
+        let id: u64 = command::parse(word, "an ingredient ID")?;
+        let range = db.fresh_range(id);
+        let fresh = if range.is_some() { "yes" } else { "no" };
+        let range = range.map_or("-".to_string(), |(start, end)| format!("{}-{}", start, end));
+        table = table.row([id.to_string(), fresh.to_string(), range]);
+    }
+    Ok(table)
+}
+
+fn ranges(db: &IngredientDatabase, _args: &[&str]) -> Result<Table, String> {
+    Ok(db.fresh_ranges().iter().fold(Table::new(&["Start", "End", "IDs"]), |table, &(start, end)| {
+        table.row([start.to_string(), end.to_string(), ((end - start) as u128 + 1).to_string()])
+    }))
+}
+
+pub struct Day05;
+
+impl Solution for Day05 {
+    const DAY: u8 = 5;
+
+    type Input = IngredientDatabase;
+
+    fn parse(input: &str) -> Result<IngredientDatabase, ParseError> {
+        input.parse()
+    }
+
+    fn part1(db: &IngredientDatabase) -> Result<impl Display, SolveError> {
+        Ok(db.count_fresh_ingredients())
+    }
+
+    fn part2(db: &IngredientDatabase) -> Result<impl Display, SolveError> {
+        Ok(db.count_all_fresh_ingredients())
+    }
+
+    /// `size` fresh ranges followed by `size * 5` ingredient IDs, about half
+    /// of which are fresh.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let ranges = (0..size)
+            .map(|_| {
+                let start = rng.range(1..=1_000_000_000_000);
+                (start, start + rng.range(0..=10_000_000_000))
+            })
+            .collect::<Vec<_>>();
+
+        let ingredients = (0..size * 5)
+            .map(|_| {
+                if rng.chance(0.5) {
+                    let &(start, end) = rng.pick(&ranges);
+                    rng.range(start..=end)
+                } else {
+                    rng.range(1..=1_011_000_000_000)
+                }
+            })
+            .map(|id| id.to_string());
+
+        let mut lines = ranges.iter().map(|(start, end)| format!("{}-{}", start, end)).collect::<Vec<_>>();
+        lines.push(String::new());
+        lines.extend(ingredients);
+        Some(lines.join("\n") + "\n")
+    }
+
+    fn commands() -> Vec<Command<IngredientDatabase>> {
+        vec![
+            Command::new("fresh", "<id>...", "Whether each ingredient is fresh, and a range that holds it", fresh),
+            Command::new("ranges", "", "The fresh ranges in input order, with how many IDs each covers", ranges),
+        ]
+    }
+}
@@ -0,0 +1,253 @@
+//! A worksheet of arithmetic problems written in columns, read by rows and
+//! then by columns.
+
+use std::fmt::Display;
+
+use aoc_core::{parse, ParseError, Rng, Solution, SolveError};
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Operator {
+    Add,
+    Multiply,
+}
+
+impl Operator {
+    /// Reads `token`, a slice of `source`, as `+` or `*`.
+    pub fn parse(source: &str, token: &str) -> Result<Operator, ParseError> {
+        match token {
+            "+" => Ok(Operator::Add),
+            "*" => Ok(Operator::Multiply),
+            _ => Err(ParseError::new(source, token, "`+` or `*`")),
+        }
+    }
+}
+
+/// Numbers to add up or multiply together.
+#[derive(Debug, Clone)]
+pub struct Problem {
+    pub nums : Vec<u64>,
+    pub operator : Operator,
+}
+
+impl Problem {
+    /// The problem's result, or `None` if it does not fit in 64 bits.
+    pub fn compute(&self) -> Option<u64> {
+        match self.operator {
+            Operator::Add => self.nums.iter().try_fold(0u64, |sum, &num| sum.checked_add(num)),
+            Operator::Multiply => self.nums.iter().try_fold(1u64, |product, &num| product.checked_mul(num)),
+        }
+    }
+
+    /// The problems with each number on its own row (part 1).
+    pub fn problems_p1(input : &str) -> Result<Vec<Problem>, ParseError> {
+        let lines = input.lines()
+            .collect::<Vec<_>>();
+        let Some((operator_line, number_lines)) = lines.split_last() else {
+            return Err(ParseError::missing(input, "a worksheet"));
+        };
+
+        let rows = number_lines.iter()
+            .map(|line| {
+                line.split_whitespace()
+                    .map(|num| parse::number::<u64>(input, num, "a number"))
+                    .collect::<Result<Vec<u64>, _>>()
+            })
+            .collect::<Result<Vec<Vec<u64>>, _>>()?;
+
+        let operators = operator_line.split_whitespace()
+            .map(|op| Operator::parse(input, op))
+            .collect::<Result<Vec<Operator>, _>>()?;
+
+        (0..operators.len())
+            .map(|i| {
+                let mut nums = vec![];
+                
+                for (row, line) in rows.iter().zip(number_lines) {
+                    let num = row.get(i)
+                        .ok_or_else(|| ParseError::missing(line, format!("{} numbers", operators.len())).rebase(input, line))?;
+                    nums.push(*num);
+                }
+
+                Ok(Problem {
+                    nums,
+                    operator: operators[i],
+                })
+            })
+            .collect()
+    }
+
+    /// The problems with each number written down a column (part 2).
+    pub fn problems_p2(input : &str) -> Result<Vec<Problem>, ParseError> {
+        let mut problems : Vec<Problem> = vec![];
+
+        let lines = input.lines()
+            .collect::<Vec<_>>();
+        let Some((operator_line, number_lines)) = lines.split_last() else {
+            return Err(ParseError::missing(input, "a worksheet"));
+        };
+        let other_lines = number_lines
+            .iter()
+            .map(|line| 
+                line.chars()
+                    .collect::<Vec<char>>()
+            )
+            .collect::<Vec<Vec<char>>>();
+
+        let mut curr_nums = vec![];
+        let mut curr_operator : Option<Operator> = None;
+
+        for (i, (byte, ch)) in operator_line.char_indices().enumerate() {
+            if ch != ' ' {
+                if let Some(operator) = curr_operator {
+                    problems.push(Problem {
+                        nums: curr_nums.clone(),
+                        operator,
+                    });
+
+                    curr_nums.clear();
+                }
+
+                curr_operator = Some(Operator::parse(input, &operator_line[byte..byte + ch.len_utf8()])?);
+            }
+
+            // Lines may be shorter than the operator line; missing cells are blank
+            let column = other_lines.iter()
+                .map(|line| line.get(i).copied().unwrap_or(' '))
+                .collect::<String>();
+
+            if column.trim().is_empty() {
+                continue;
+            }
+
+            let my_num = column.trim().parse::<u64>().map_err(|_| {
+                let cells = number_lines.iter()
+                    .filter_map(|line| line.char_indices().nth(i).map(|(byte, ch)| &line[byte..byte + ch.len_utf8()]))
+                    .collect::<Vec<&str>>();
+
+                // Point at the first cell of the column that is not a digit,
+                // or else at the start of a number with gaps or too many
+                // digits
+                match cells.iter().find(|cell| !cell.bytes().all(|b| b.is_ascii_digit() || b == b' ')) {
+                    Some(cell) => ParseError::new(input, cell, "a digit"),
+                    None => {
+                        let first = cells.iter().find(|&&cell| cell != " ").copied().unwrap_or("");
+                        ParseError::new(input, first, "a column of digits making a 64-bit number")
+                    }
+                }
+            })?;
+            
+            curr_nums.push(my_num);
+        }
+
+        let operator = curr_operator
+            .ok_or_else(|| ParseError::new(input, operator_line, "a line of operators"))?;
+        problems.push(Problem {
+            nums: curr_nums.clone(),
+            operator,
+        });
+
+        Ok(problems)
+    }
+}
+
+/// The worksheet read both ways: row by row for part 1 and column by column
+/// for part 2.
+#[derive(Debug, Clone)]
+pub struct Worksheet {
+    problems_p1: Vec<Problem>,
+    problems_p2: Vec<Problem>,
+}
+
+impl Worksheet {
+    pub fn problems_p1(&self) -> &[Problem] {
+        &self.problems_p1
+    }
+
+    pub fn problems_p2(&self) -> &[Problem] {
+        &self.problems_p2
+    }
+
+    /// The sum of every problem's result, read by rows (part 1).
+    pub fn grand_total_p1(&self) -> Result<u64, SolveError> {
+        grand_total(&self.problems_p1)
+    }
+
+    /// The sum of every problem's result, read by columns (part 2).
+    pub fn grand_total_p2(&self) -> Result<u64, SolveError> {
+        grand_total(&self.problems_p2)
+    }
+}
+
+fn grand_total(problems: &[Problem]) -> Result<u64, SolveError> {
+    problems.iter()
+        .enumerate()
+        .try_fold(0u64, |total, (i, problem)| {
+            let result = problem.compute()
+                .ok_or_else(|| SolveError::new(format!("problem {} does not fit in 64 bits", i + 1)))?;
+            total.checked_add(result)
+                .ok_or_else(|| SolveError::new("the grand total does not fit in 64 bits"))
+        })
+}
+
+impl std::str::FromStr for Worksheet {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let input = input.replace("\r", "");
+
+        Ok(Worksheet {
+            problems_p1: Problem::problems_p1(&input)?,
+            problems_p2: Problem::problems_p2(&input)?,
+        })
+    }
+}
+
+pub struct Day06;
+
+impl Solution for Day06 {
+    const DAY: u8 = 6;
+
+    type Input = Worksheet;
+
+    fn parse(input: &str) -> Result<Worksheet, ParseError> {
+        input.parse()
+    }
+
+    fn part1(worksheet: &Worksheet) -> Result<impl Display, SolveError> {
+        worksheet.grand_total_p1()
+    }
+
+    fn part2(worksheet: &Worksheet) -> Result<impl Display, SolveError> {
+        worksheet.grand_total_p2()
+    }
+
+    /// A worksheet of `size` problems, each four numbers of up to three
+    /// digits aligned left or right in their column.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const ROWS: usize = 4;
+        let mut lines = vec![String::new(); ROWS + 1];
+
+        for problem in 0..size.max(1) {
+            // Longer numbers come first, as in the example, so every column
+            // read downwards is one unbroken number.
+            let mut numbers = (0..ROWS).map(|_| rng.range(1..=999).to_string()).collect::<Vec<_>>();
+            numbers.sort_by_key(|number| std::cmp::Reverse(number.len()));
+            let width = numbers.iter().map(String::len).max().unwrap_or(1);
+            let left_aligned = rng.chance(0.5);
+
+            if problem > 0 {
+                lines.iter_mut().for_each(|line| line.push(' '));
+            }
+            for (line, number) in lines.iter_mut().zip(&numbers) {
+                if left_aligned {
+                    *line += &format!("{:<width$}", number);
+                } else {
+                    *line += &format!("{:>width$}", number);
+                }
+            }
+            lines[ROWS] += &format!("{:<width$}", rng.pick(&["+", "*"]));
+        }
+
+        Some(lines.join("\n") + "\n")
+    }
+}
@@ -0,0 +1,306 @@
+//! A tachyon manifold: a beam entering at `S` and heading down, split in
+//! two by every splitter `^` it meets.
+
+use std::{collections::{HashMap, HashSet}, fmt::Display};
+
+use aoc_core::{trace, Color, Frame, Glyph, Grid, ParseError, Rng, Scene, Solution, SolveError, Style, Vec2};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Tile {
+    Empty,
+    Start,
+    Splitter
+}
+
+/// The manifold's tiles and where the beam enters.
+#[derive(Debug, Clone)]
+pub struct Manifold {
+    tiles: Grid<Tile>,
+    start : Vec2<usize>,
+}
+
+impl std::str::FromStr for Manifold {
+    type Err = ParseError;
+
+    fn from_str(value: &str) -> Result<Self, ParseError> {
+        let tiles = Grid::parse(value, "`.`, `|`, `^` or `S`", |ch| match ch {
+            'S' => Some(Tile::Start),
+            '^' => Some(Tile::Splitter),
+            // Beams drawn into the diagram are ignored
+            '.' | '|' => Some(Tile::Empty),
+            _ => None,
+        })?;
+
+        let start = tiles.find(|&tile| tile == Tile::Start)
+            .ok_or_else(|| ParseError::missing(value, "a start tile `S`"))?;
+
+        Ok(Self {
+            tiles,
+            start,
+        })
+    }
+}
+
+impl Manifold {
+    pub fn tiles(&self) -> &Grid<Tile> {
+        &self.tiles
+    }
+
+    pub fn start(&self) -> Vec2<usize> {
+        self.start
+    }
+
+    fn scene(&self) -> Scene {
+        let mut scene = Scene::new();
+        scene.set_title(&format!("{} splits", self.count_splits()));
+        scene.grid(&self.tiles, |tile| match tile {
+            Tile::Empty => None,
+            Tile::Start => Some("green"),
+            Tile::Splitter => Some("black"),
+        });
+        scene.legend(Style::fill("green").label("start"));
+        scene.legend(Style::fill("black").label("splitter"));
+        scene
+    }
+
+    /// One frame per row the beams reach, drawing the beams so far and the
+    /// splitters they have hit.
+    fn beam_frames(&self) -> Vec<Frame> {
+        let mut beams = self.tiles.map(|_, _| false);
+        let mut hit = self.tiles.map(|_, _| false);
+        let mut front = vec![self.start.x];
+        let mut splits = 0;
+        let mut frames = vec![];
+
+        for y in self.start.y..self.tiles.height() {
+            let mut next = vec![];
+            for &x in &front {
+                let pos = Vec2::new(x, y);
+                if self.tiles[pos] == Tile::Splitter {
+                    if !hit[pos] {
+                        hit[pos] = true;
+                        splits += 1;
+                    }
+                    next.extend(x.checked_sub(1));
+                    next.extend(Some(x + 1).filter(|&x| x < self.tiles.width()));
+                } else {
+                    beams[pos] = true;
+                    next.push(x);
+                }
+            }
+            next.sort_unstable();
+            next.dedup();
+            front = next;
+
+            frames.push(Frame::from_grid(format!("row {}: {} splits", y, splits), &self.tiles, |pos, tile| {
+                match tile {
+                    Tile::Start => Glyph::new('S', Color::Green),
+                    Tile::Splitter if hit[pos] => Glyph::new('^', Color::Red),
+                    Tile::Splitter => Glyph::new('^', Color::Gray),
+                    Tile::Empty if beams[pos] => Glyph::new('|', Color::Yellow),
+                    Tile::Empty => Glyph::new('.', Color::Default),
+                }
+            }));
+        }
+
+        frames
+    }
+
+    /// How many splitters a beam reaches (part 1).
+    pub fn count_splits(&self) -> usize {
+        let mut count = 0;
+
+        let mut beams = vec![self.start];
+        let mut visited = HashSet::new();
+
+        while let Some(pos) = beams.pop() {
+
+            if pos.y + 1 >= self.tiles.height() || pos.x >= self.tiles.width() {
+                continue;
+            }
+
+            if visited.contains(&pos) {
+                continue;
+            }
+            visited.insert(pos);
+
+            if self.tiles[pos + Vec2::y(1)] == Tile::Splitter {
+                count += 1;
+
+                if pos.x > 0 {
+                    beams.push(Vec2::new(pos.x - 1, pos.y + 1));
+                }
+                beams.push(Vec2::new(pos.x + 1, pos.y + 1));
+            } else {
+                beams.push(Vec2::new(pos.x, pos.y + 1));
+            }
+        }
+        count
+    }
+
+    /// How many paths a single particle can take through the manifold
+    /// (part 2), or `None` if there are too many to count in a `usize`. A
+    /// particle split off the side of the manifold leaves it there.
+    pub fn count_timelines(&self) -> Option<usize> {
+        let mut nodes = TimelineNode::from_manifold(self);
+        if nodes.is_empty() {
+            // The beam falls straight through without meeting a splitter
+            return Some(1);
+        }
+        TimelineNode::calculate_timelines(&mut nodes)
+    }
+
+    /// The first splitter a beam at `pos` meets on its way down, if it meets
+    /// one before leaving the manifold.
+    fn splitter_below(&self, pos: Vec2<usize>) -> Option<Vec2<usize>> {
+        (pos.y + 1..self.tiles.height())
+            .map(|y| Vec2::new(pos.x, y))
+            .find(|&below| self.tiles.get(below) == Some(&Tile::Splitter))
+    }
+}
+
+#[derive(Debug)]
+enum NextNode {
+    None,
+    Single(usize),
+    Double(usize, usize),
+}
+
+#[derive(Debug)]
+struct TimelineNode {
+    pos : Vec2<usize>,
+    next : NextNode,
+    calculated_timelines : Option<usize>
+}
+
+impl TimelineNode {
+    fn from_manifold(manifold : &Manifold) -> Vec<TimelineNode> {
+        let mut nodes = vec![
+        ];
+        let mut pos_to_index : HashMap<Vec2<usize>, usize> = HashMap::new();
+
+        let mut node_queue : Vec<usize> = vec![];
+
+        let Some(pos) = manifold.splitter_below(manifold.start) else {
+            return nodes;
+        };
+
+        let start_node = TimelineNode {
+            pos,
+            next: NextNode::None,
+            calculated_timelines: None
+        };
+        nodes.push(start_node);
+        pos_to_index.insert(pos, 0);
+        node_queue.push(0);
+
+        while let Some(node_index) = node_queue.pop() {
+            let pos = nodes[node_index].pos;
+
+            // The beams either side of the splitter, which carry on down
+            // from its row
+            for side in [pos.x.checked_sub(1), Some(pos.x + 1)] {
+                let Some(next) = side.and_then(|x| manifold.splitter_below(Vec2::new(x, pos.y))) else {
+                    continue;
+                };
+
+                let next_index = match pos_to_index.get(&next) {
+                    Some(&next_index) => next_index,
+                    None => {
+                        let next_node = TimelineNode {
+                            pos: next,
+                            next: NextNode::None,
+                            calculated_timelines: None
+                        };
+                        nodes.push(next_node);
+                        let next_index = nodes.len() - 1;
+                        pos_to_index.insert(next, next_index);
+                        node_queue.push(next_index);
+                        next_index
+                    }
+                };
+
+                nodes[node_index].next = match nodes[node_index].next {
+                    NextNode::None => NextNode::Single(next_index),
+                    NextNode::Single(first) => NextNode::Double(first, next_index),
+                    NextNode::Double(_, _) => panic!("A node cannot have more than two next nodes"),
+                };
+            }
+        }
+
+        trace::count("timeline nodes built", nodes.len() as u64);
+        nodes
+    }
+
+    /// The timelines from the first node, or `None` if they overflow.
+    fn calculate_timelines(nodes : &mut [TimelineNode]) -> Option<usize> {
+        // Splitters only lead to splitters further down, so working up from
+        // the bottom finds every node's next nodes already counted
+        let mut order = (0..nodes.len()).collect::<Vec<_>>();
+        order.sort_by_key(|&index| std::cmp::Reverse(nodes[index].pos.y));
+
+        for index in order {
+            let count = match nodes[index].next {
+                NextNode::None => 2,
+                NextNode::Single(next) => nodes[next].calculated_timelines?.checked_add(1)?,
+                NextNode::Double(first, second) => {
+                    nodes[first].calculated_timelines?.checked_add(nodes[second].calculated_timelines?)?
+                }
+            };
+            nodes[index].calculated_timelines = Some(count);
+        }
+
+        nodes[0].calculated_timelines
+    }
+}
+
+pub struct Day07;
+
+impl Solution for Day07 {
+    const DAY: u8 = 7;
+
+    type Input = Manifold;
+
+    fn parse(input: &str) -> Result<Manifold, ParseError> {
+        input.parse()
+    }
+
+    fn part1(manifold: &Manifold) -> Result<impl Display, SolveError> {
+        Ok(manifold.count_splits())
+    }
+
+    fn part2(manifold: &Manifold) -> Result<impl Display, SolveError> {
+        manifold.count_timelines()
+            .ok_or_else(|| SolveError::new("there are more timelines than fit in 64 bits"))
+    }
+
+    fn render(manifold: &Manifold) -> Option<Scene> {
+        Some(manifold.scene())
+    }
+
+    fn animate(manifold: &Manifold) -> Option<Vec<Frame>> {
+        Some(manifold.beam_frames())
+    }
+
+    /// A `size` by `size` manifold with `S` in the middle of the top row and
+    /// splitters on every other row below it, spreading out like the beams.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(3);
+        let start = size / 2;
+        let mut rows = vec![vec!['.'; size]; size];
+        rows[0][start] = 'S';
+
+        for (level, y) in (2..size).step_by(2).enumerate() {
+            // Splitters sit where beams can arrive: every other column within
+            // `level` of the start, never on the edges.
+            for x in (start.saturating_sub(level)..=start + level).step_by(2) {
+                if (1..size - 1).contains(&x) && (level == 0 || rng.chance(0.6)) {
+                    rows[y][x] = '^';
+                }
+            }
+        }
+
+        let lines = rows.into_iter().map(String::from_iter).collect::<Vec<_>>();
+        Some(lines.join("\n") + "\n")
+    }
+}
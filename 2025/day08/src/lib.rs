@@ -0,0 +1,221 @@
+//! Junction boxes in 3D space, joined into circuits closest pair first.
+
+use std::{collections::{HashMap, HashSet}, fmt::Display};
+
+use aoc_core::{parallel, parse, ParseError, Rng, Solution, SolveError, Vec3};
+
+/// Reads a junction box's position, like `162,817,812`.
+pub fn get_box(line : &str) -> Result<Vec3<i32>, ParseError> {
+    let parts: Vec<&str> = line.trim().split(',').collect();
+    if parts.len() != 3 {
+        return Err(ParseError::new(line, line, "three coordinates like `162,817,812`"));
+    }
+
+    let x: i32 = parse::number(line, parts[0], "an x coordinate")?;
+    let y: i32 = parse::number(line, parts[1], "a y coordinate")?;
+    let z: i32 = parse::number(line, parts[2], "a z coordinate")?;
+    Ok(Vec3::new(x, y, z))
+}
+
+fn connect(bx1 : Vec3<i32>, bx2 : Vec3<i32>, circuits : &mut Vec<Vec<Vec3<i32>>>, box_map : &mut HashMap<Vec3<i32>, usize>) {
+    let in_map1 = box_map.get(&bx1).cloned();
+    let in_map2 = box_map.get(&bx2).cloned();
+
+    match (in_map1, in_map2) {
+        (Some(index1), Some(index2)) => {
+            if index1 != index2 {
+                merge_circuits(index1, index2, circuits, box_map);
+            }
+        },
+        (Some(index1), None) => {
+            add_to_circuit(bx2, index1, circuits, box_map);
+        },
+        (None, Some(index2)) => {
+            add_to_circuit(bx1, index2, circuits, box_map);
+        },
+        (None, None) => {
+            create_circuit(bx1, bx2, circuits, box_map);
+        }
+    }
+}
+
+fn create_circuit(bx1 : Vec3<i32>, bx2 : Vec3<i32>, circuits : &mut Vec<Vec<Vec3<i32>>>, box_map : &mut HashMap<Vec3<i32>, usize>) {
+    let new_circuit = vec![bx1, bx2];
+    let index = circuits.len();
+    circuits.push(new_circuit);
+    box_map.insert(bx1, index);
+    box_map.insert(bx2, index);
+}
+
+fn add_to_circuit(bx : Vec3<i32>, index : usize, circuits : &mut [Vec<Vec3<i32>>], box_map : &mut HashMap<Vec3<i32>, usize>) {
+    circuits[index].push(bx);
+    box_map.insert(bx, index);
+}
+
+fn merge_circuits(index1 : usize, index2 : usize, circuits : &mut [Vec<Vec3<i32>>], box_map : &mut HashMap<Vec3<i32>, usize>) {
+    let circuit2 = circuits[index2].clone();
+
+    // Merge circuit2 into circuit1
+    circuits[index1].extend(circuit2.iter().cloned());
+
+    // Update box_map to point to the merged circuit
+    for b in &circuit2 {
+        box_map.insert(*b, index1);
+    }
+
+    // Clear circuit2
+    circuits[index2].clear();
+}
+
+/// Every ordered pair of distinct boxes with the distance between them,
+/// closest first.
+pub fn sorted_distances(boxes : &[Vec3<i32>]) -> Vec<(Vec3<i32>, Vec3<i32>, f64)> {
+    let mut distances : Vec<(Vec3<i32>, Vec3<i32>, f64)> = parallel::map(boxes, |bx1| {
+        boxes.iter()
+            .filter(|bx2| bx1 != *bx2)
+            .map(|bx2| (*bx1, *bx2, bx1.euclidean(bx2)))
+            .collect::<Vec<_>>()
+    })
+    .into_iter()
+    .flatten()
+    .collect();
+
+    distances.sort_by_key(|(_, _, d)| *d as u64);
+    distances
+}
+
+/// The circuits after joining the `connections_count` closest pairs of
+/// boxes, including boxes left on their own. With fewer pairs than that,
+/// every pair is joined.
+pub fn get_circuits_p1(connections_count : usize, boxes : &[Vec3<i32>]) -> Vec<Vec<Vec3<i32>>> {
+    let mut box_map : HashMap<Vec3<i32>, usize> = HashMap::new();
+    let mut circuits : Vec<Vec<Vec3<i32>>> = vec![];
+    let mut connected_pairs : HashSet<(Vec3<i32>, Vec3<i32>)> = HashSet::new();
+
+    let distances = sorted_distances(boxes);
+
+    let mut count = 0;
+
+    for &(bx1, bx2, _) in &distances {
+        if count == connections_count {
+            break;
+        }
+
+        if connected_pairs.contains(&(bx1, bx2)) || connected_pairs.contains(&(bx2, bx1)) {
+            continue;
+        }
+
+        connect(bx1, bx2, &mut circuits, &mut box_map);
+        connected_pairs.insert((bx1, bx2));
+        count += 1;
+    }
+
+    for bx in boxes {
+        if !box_map.contains_key(bx) {
+            // Create a new circuit for this box
+            let index = circuits.len();
+            circuits.push(vec![*bx]);
+            box_map.insert(*bx, index);
+        }
+    }
+
+    // Get rid of empty placeholder circuits
+    circuits.into_iter().filter(|circuit| !circuit.is_empty()).collect()
+}
+
+/// Joins the closest pairs until every box is in one circuit, and returns
+/// the product of the x coordinates of the last pair joined (part 2). `None`
+/// if the boxes never form one circuit, as when there are fewer than two or
+/// two share a position.
+pub fn get_circuits_p2(boxes : &[Vec3<i32>]) -> Option<i64> {
+    let mut box_map : HashMap<Vec3<i32>, usize> = HashMap::new();
+    let mut circuits : Vec<Vec<Vec3<i32>>> = vec![];
+    let mut connected_pairs : HashSet<(Vec3<i32>, Vec3<i32>)> = HashSet::new();
+
+    let distances = sorted_distances(boxes);
+
+    for &(bx1, bx2, _) in &distances {
+        if connected_pairs.contains(&(bx1, bx2)) || connected_pairs.contains(&(bx2, bx1)) {
+            continue;
+        }
+
+        connect(bx1, bx2, &mut circuits, &mut box_map);
+        connected_pairs.insert((bx1, bx2));
+
+        if circuits[box_map[&bx1]].len() == boxes.len() {
+            return Some(bx1.x as i64 * bx2.x as i64);
+        }
+    }
+
+    None
+}
+
+/// How many of the closest pairs part 1 joins. The example joins only 10,
+/// which [`largest_circuits_product`] can be asked for directly.
+pub const CONNECTIONS: usize = 1000;
+
+/// The product of the sizes of the three largest circuits after joining the
+/// `connections_count` closest pairs (part 1).
+pub fn largest_circuits_product(connections_count : usize, boxes : &[Vec3<i32>]) -> usize {
+    let mut circuits = get_circuits_p1(connections_count, boxes);
+    circuits.sort_by_key(|c| std::cmp::Reverse(c.len()));
+
+    circuits.iter()
+        .take(3)
+        .map(|c| c.len())
+        .product::<usize>()
+}
+
+pub struct Day08;
+
+impl Solution for Day08 {
+    const DAY: u8 = 8;
+
+    type Input = Vec<Vec3<i32>>;
+
+    fn parse(input: &str) -> Result<Vec<Vec3<i32>>, ParseError> {
+        let mut seen = HashSet::new();
+
+        input
+            .lines()
+            .map(|line| {
+                let bx = get_box(line).map_err(|err| err.rebase(input, line))?;
+                if !seen.insert(bx) {
+                    return Err(ParseError::new(input, line, "a box at a position of its own"));
+                }
+                Ok(bx)
+            })
+            .collect()
+    }
+
+    fn part1(boxes: &Vec<Vec3<i32>>) -> Result<impl Display, SolveError> {
+        let pairs = boxes.len() * boxes.len().saturating_sub(1) / 2;
+        if pairs < CONNECTIONS {
+            return Err(SolveError::new(format!("{} boxes make {} pairs, too few for {} connections", boxes.len(), pairs, CONNECTIONS)));
+        }
+
+        Ok(largest_circuits_product(CONNECTIONS, boxes))
+    }
+
+    fn part2(boxes: &Vec<Vec3<i32>>) -> Result<impl Display, SolveError> {
+        get_circuits_p2(boxes).ok_or_else(|| SolveError::new("there must be at least two boxes to join"))
+    }
+
+    /// `size` junction boxes at distinct points with coordinates below
+    /// 100000. Sizes below 46, too few for [`CONNECTIONS`] pairs, are raised
+    /// to 46.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(46);
+
+        let mut seen = HashSet::new();
+        let mut lines = vec![];
+        while lines.len() < size {
+            let point = (rng.range(0..=99_999), rng.range(0..=99_999), rng.range(0..=99_999));
+            if seen.insert(point) {
+                lines.push(format!("{},{},{}", point.0, point.1, point.2));
+            }
+        }
+
+        Some(lines.join("\n") + "\n")
+    }
+}
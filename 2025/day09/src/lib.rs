@@ -0,0 +1,335 @@
+//! Red tiles at the corners of a rectilinear loop, green tiles along and
+//! inside it, and the largest rectangles with red tiles at two corners.
+
+use std::fmt::Display;
+
+use aoc_core::{parse, Grid, Line2, ParseError, Rect, Rng, Scene, Solution, SolveError, Style, Vec2};
+use itertools::Itertools;
+
+type Point2d = Vec2<i64>;
+type Line2D = Line2<i64>;
+
+/// Reads a red tile, like `7,1`. Coordinates fit in 32 bits, so no
+/// distance or area between tiles overflows.
+pub fn parse_point(line: &str) -> Result<Point2d, ParseError> {
+    let (x, y) = line.split_once(',')
+        .ok_or_else(|| ParseError::new(line, line, "a point like `7,1`"))?;
+    let x: i32 = parse::number(line, x.trim(), "a 32-bit x coordinate")?;
+    let y: i32 = parse::number(line, y.trim(), "a 32-bit y coordinate")?;
+    Ok(Point2d::new(x as i64, y as i64))
+}
+
+/// Area of the rectangle with opposite corners `p1` and `p2`, counting tiles
+/// inclusively. Taken in 128 bits, as two 32-bit spans multiply past 64.
+pub fn area(p1: &Point2d, p2: &Point2d) -> i128 {
+    ((p1.x - p2.x).abs() as i128 + 1) * ((p1.y - p2.y).abs() as i128 + 1)
+}
+
+/// Makes sure `points` go around a rectilinear loop: at least four of them,
+/// each in line with the one before it, turning a corner at every one.
+/// `lines` are the input lines they were read from, to point errors at.
+fn check_loop(input: &str, lines: &[&str], points: &[Point2d]) -> Result<(), ParseError> {
+    if points.len() < 4 {
+        return Err(ParseError::missing(input, "at least four red tiles"));
+    }
+
+    // Whether the edge into each point is vertical
+    let vertical = (0..points.len())
+        .map(|i| {
+            let (previous, point) = (points[(i + points.len() - 1) % points.len()], points[i]);
+            match (previous.x == point.x, previous.y == point.y) {
+                (true, false) => Ok(true),
+                (false, true) => Ok(false),
+                _ => Err(ParseError::new(input, lines[i], "a tile in line with the one before it")),
+            }
+        })
+        .collect::<Result<Vec<_>, _>>()?;
+
+    match (0..points.len()).find(|&i| vertical[i] == vertical[(i + 1) % points.len()]) {
+        Some(i) => Err(ParseError::new(input, lines[i], "a tile at a corner of the loop")),
+        None => Ok(()),
+    }
+}
+
+/// The floor squeezed down to the coordinates that matter. Each x (and y)
+/// of a red tile gets a column (row) of its own, and each run of coordinates
+/// strictly between two of them shares one, since no edge starts or ends
+/// inside it and so every tile in a cell has the same color.
+#[derive(Debug, Clone)]
+struct Floor {
+    xs: Vec<i64>,
+    ys: Vec<i64>,
+    /// How many cells up to and excluding each position hold tiles that are
+    /// neither red nor green, so any rectangle can be checked at once.
+    outside_before: Grid<u32>,
+}
+
+impl Floor {
+    fn new(points: &[Point2d]) -> Self {
+        let xs = points.iter().map(|p| p.x).sorted().dedup().collect::<Vec<_>>();
+        let ys = points.iter().map(|p| p.y).sorted().dedup().collect::<Vec<_>>();
+        let column = |x: i64| 2 * xs.binary_search(&x).unwrap();
+        let row = |y: i64| 2 * ys.binary_search(&y).unwrap();
+
+        // A tile standing for each cell, or `None` for runs with no tiles
+        let tile = |coords: &[i64], cell: usize| match cell % 2 {
+            0 => Some(coords[cell / 2]),
+            _ => Some(coords[cell / 2] + 1).filter(|&next| next < coords[cell / 2 + 1]),
+        };
+
+        let edges = points.iter().zip(points.iter().cycle().skip(1)).collect::<Vec<_>>();
+        let (width, height) = (2 * xs.len() - 1, 2 * ys.len() - 1);
+        let mut colored = Grid::new(width, height, false);
+
+        for &(a, b) in &edges {
+            for x in column(a.x.min(b.x))..=column(a.x.max(b.x)) {
+                for y in row(a.y.min(b.y))..=row(a.y.max(b.y)) {
+                    colored[Vec2::new(x, y)] = true;
+                }
+            }
+        }
+
+        for cy in 0..height {
+            let Some(y) = tile(&ys, cy) else { continue };
+
+            // A tile off the edges is inside if a ray to its right crosses
+            // an odd number of vertical edges. Counting edges half-open in y
+            // counts a ray through a corner once.
+            let crossings = edges
+                .iter()
+                .filter(|(a, b)| a.x == b.x && a.y.min(b.y) <= y && y < a.y.max(b.y))
+                .map(|(a, _)| a.x)
+                .sorted()
+                .collect::<Vec<_>>();
+
+            for cx in 0..width {
+                let Some(x) = tile(&xs, cx) else { continue };
+                if (crossings.len() - crossings.partition_point(|&edge| edge <= x)) % 2 == 1 {
+                    colored[Vec2::new(cx, cy)] = true;
+                }
+            }
+        }
+
+        let mut outside_before = Grid::new(width + 1, height + 1, 0);
+        for cy in 0..height {
+            for cx in 0..width {
+                let outside = tile(&xs, cx).is_some() && tile(&ys, cy).is_some() && !colored[Vec2::new(cx, cy)];
+                outside_before[Vec2::new(cx + 1, cy + 1)] = outside as u32
+                    + outside_before[Vec2::new(cx, cy + 1)]
+                    + outside_before[Vec2::new(cx + 1, cy)]
+                    - outside_before[Vec2::new(cx, cy)];
+            }
+        }
+
+        Self { xs, ys, outside_before }
+    }
+
+    /// Whether every tile of the rectangle between red tiles `p1` and `p2`
+    /// is red or green.
+    fn is_covered(&self, p1: Point2d, p2: Point2d) -> bool {
+        let column = |x: i64| 2 * self.xs.binary_search(&x).unwrap();
+        let row = |y: i64| 2 * self.ys.binary_search(&y).unwrap();
+        let (x1, x2) = (column(p1.x.min(p2.x)), column(p1.x.max(p2.x)) + 1);
+        let (y1, y2) = (row(p1.y.min(p2.y)), row(p1.y.max(p2.y)) + 1);
+
+        let before = |x, y| self.outside_before[Vec2::new(x, y)];
+        before(x2, y2) + before(x1, y1) == before(x1, y2) + before(x2, y1)
+    }
+}
+
+/// The red tiles in input order, plus the polygon edges they form.
+#[derive(Debug, Clone)]
+pub struct Polygon {
+    points: Vec<Point2d>,
+    vertical_slices: Vec<Line2D>,
+    horizontal_slices: Vec<Line2D>,
+    floor: Floor,
+}
+
+impl Polygon {
+    /// The loop through `points`, which [`check_loop`] has found to go
+    /// around a rectilinear loop.
+    fn new(mut points: Vec<Point2d>) -> Self {
+        // ensure every 2 points have same x coord
+        if points[0].x != points[1].x {
+            let p = points.remove(0);
+            points.push(p);
+        }
+
+        let vertical_slices : Vec<Line2D> = points.iter()
+            .chunks(2)
+            .into_iter()
+            .map(|mut chunk| {
+                let p1 = chunk.next().unwrap();
+                let p2 = chunk.next().unwrap();
+                Line2D { start: *p1, end: *p2 }
+            })
+            .collect();
+
+        // cycle the points 
+        let rm = points.remove(0);
+        points.push(rm);
+
+        let horizontal_slices : Vec<Line2D> = points.iter()
+            .chunks(2)
+            .into_iter()
+            .map(|mut chunk| {
+                let p1 = chunk.next().unwrap();
+                let p2 = chunk.next().unwrap();
+                Line2D { start: *p1, end: *p2 }
+            })
+            .collect();
+
+        let floor = Floor::new(&points);
+
+        Self {
+            points,
+            vertical_slices,
+            horizontal_slices,
+            floor,
+        }
+    }
+
+    /// The red tiles, in order around the loop.
+    pub fn points(&self) -> &[Point2d] {
+        &self.points
+    }
+
+    /// The area of the largest rectangle between two red tiles (part 1).
+    pub fn largest_area(&self) -> i128 {
+        self.points
+            .iter()
+            .cartesian_product(self.points.iter())
+            .map(|(p1, p2)| area(p1, p2))
+            .max()
+            .expect("a loop has red tiles")
+    }
+
+    /// The largest rectangle between two red tiles that stays inside the
+    /// polygon, as its two corners and its area.
+    pub fn largest_valid_rect(&self) -> (Point2d, Point2d, i128) {
+        self.points
+            .iter()
+            .cartesian_product(self.points.iter())
+            .filter_map(|(p1, p2)| {
+                if !self.floor.is_covered(*p1, *p2) {
+                    return None;
+                }
+
+                Some((*p1, *p2, area(p1, p2)))
+            })
+            .max_by_key(|(_, _, area)| *area)
+            .expect("a red tile on its own is a rectangle inside the loop")
+    }
+
+    /// The polygon edges and the part 2 rectangle. Edges crossing the
+    /// rectangle's outline are highlighted.
+    fn scene(&self) -> Scene {
+        let (p1, p2, area) = self.largest_valid_rect();
+        let rect = Rect { p1, p2 };
+
+        let mut scene = Scene::new();
+        scene.set_title(&format!("Largest rectangle inside the tiles: {}", area));
+
+        for segment in &self.vertical_slices {
+            let style = if rect.intersects_line(segment) {
+                Style::stroke("purple").label("vertical edge touching the rectangle")
+            } else {
+                Style::stroke("blue").label("vertical edge")
+            };
+            scene.line(segment, style);
+        }
+
+        for segment in &self.horizontal_slices {
+            let style = if rect.intersects_line(segment) {
+                Style::stroke("orange").label("horizontal edge touching the rectangle")
+            } else {
+                Style::stroke("red").label("horizontal edge")
+            };
+            scene.line(segment, style);
+        }
+
+        scene.rect(&rect, Style::stroke("green").width(2.0).label("largest rectangle"));
+        scene
+    }
+}
+
+/// Reads a red tile per line, in order around the loop, and makes sure
+/// they go around a rectilinear loop.
+impl std::str::FromStr for Polygon {
+    type Err = ParseError;
+
+    fn from_str(input: &str) -> Result<Self, ParseError> {
+        let lines = input.lines().collect::<Vec<_>>();
+        let points = lines.iter()
+            .map(|line| parse_point(line).map_err(|err| err.rebase(input, line)))
+            .collect::<Result<Vec<_>, _>>()?;
+        check_loop(input, &lines, &points)?;
+
+        Ok(Polygon::new(points))
+    }
+}
+
+pub struct Day09;
+
+impl Solution for Day09 {
+    const DAY: u8 = 9;
+
+    type Input = Polygon;
+
+    fn parse(input: &str) -> Result<Polygon, ParseError> {
+        input.parse()
+    }
+
+    fn part1(polygon: &Polygon) -> Result<impl Display, SolveError> {
+        Ok(polygon.largest_area())
+    }
+
+    fn part2(polygon: &Polygon) -> Result<impl Display, SolveError> {
+        let (_, _, area) = polygon.largest_valid_rect();
+        Ok(area)
+    }
+
+    fn render(polygon: &Polygon) -> Option<Scene> {
+        Some(polygon.scene())
+    }
+
+    /// A rectilinear polygon with `4 * size` red tiles: a skyline over
+    /// `size` columns above an upside-down skyline below.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let size = size.max(1);
+
+        let mut xs = vec![rng.range(1..=1_000) as i64];
+        for _ in 0..size {
+            xs.push(xs[xs.len() - 1] + rng.range(1..=2_000) as i64);
+        }
+
+        // Heights differ from their neighbours so every corner turns, and
+        // the top stays above the bottom so the outline never crosses.
+        let mut heights = |low: u64, high: u64| {
+            let mut heights: Vec<i64> = vec![];
+            while heights.len() < size {
+                let height = rng.range(low..=high) as i64;
+                if heights.last() != Some(&height) {
+                    heights.push(height);
+                }
+            }
+            heights
+        };
+        let top = heights(50_000, 99_999);
+        let bottom = heights(1, 49_999);
+
+        let mut points = vec![];
+        for i in 0..size {
+            points.push((xs[i], top[i]));
+            points.push((xs[i + 1], top[i]));
+        }
+        for i in (0..size).rev() {
+            points.push((xs[i + 1], bottom[i]));
+            points.push((xs[i], bottom[i]));
+        }
+
+        let lines = points.iter().map(|(x, y)| format!("{},{}", x, y)).collect::<Vec<_>>();
+        Some(lines.join("\n") + "\n")
+    }
+}
@@ -0,0 +1,358 @@
+//! Factory machines: buttons toggling indicator lights, or raising joltage
+//! counters, and the fewest presses that reach a target.
+
+use std::{collections::HashMap, fmt::Display};
+
+use aoc_core::{command, parallel, parse, trace, Command, ParseError, Rng, Solution, SolveError, Table};
+
+/// One machine, like `[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}`:
+/// the lights to turn on, the lights or counters each button affects, and
+/// the joltage each counter must reach.
+#[derive(Clone, Debug, Hash, PartialEq, Eq)]
+pub struct Machine {
+    goal : Vec<bool>,
+    buttons : Vec<Vec<usize>>,
+    joltage_goal : Vec<usize>,
+}
+
+/// The most states a search for the fewest presses expands on one machine
+/// before giving up. Every machine in the puzzle input needs far fewer.
+pub const SEARCH_LIMIT: usize = 1_000_000;
+
+/// Why a machine has no fewest number of presses.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SearchError {
+    /// No presses reach the goal.
+    Unreachable,
+    /// The search expanded [`SEARCH_LIMIT`] states without finishing.
+    TooLong,
+}
+
+impl Display for SearchError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SearchError::Unreachable => write!(f, "no presses reach its goal"),
+            SearchError::TooLong => write!(f, "the search gave up after {} states", SEARCH_LIMIT),
+        }
+    }
+}
+
+struct P1SearchNode {
+    lights : Vec<bool>,
+    presses : usize,
+}
+
+impl P1SearchNode {
+    fn is_solved(&self, machine : &Machine) -> bool {
+        self.lights == machine.goal
+    }
+
+    fn press(&self, machine : &Machine, button_index : usize) -> P1SearchNode {
+        let mut new_lights = self.lights.clone();
+        for &light_index in &machine.buttons[button_index] {
+            new_lights[light_index] = !new_lights[light_index];
+        }
+
+        P1SearchNode {
+            lights: new_lights,
+            presses: self.presses + 1,
+        }
+    }
+}
+
+/// The effect of pressing some set of buttons once each: how much each
+/// counter rises, and how many buttons that takes.
+struct Combination {
+    raises : Vec<usize>,
+    presses : usize,
+}
+
+impl Combination {
+    /// Which counters this combination raises by an odd amount.
+    fn parity(&self) -> Vec<bool> {
+        self.raises.iter().map(|raise| raise % 2 == 1).collect()
+    }
+}
+
+impl Machine {
+    pub fn new(goal: Vec<bool>, buttons: Vec<Vec<usize>>, joltage_goal: Vec<usize>) -> Self {
+        Machine { goal, buttons, joltage_goal }
+    }
+
+    /// Which lights must end up on.
+    pub fn goal(&self) -> &[bool] {
+        &self.goal
+    }
+
+    /// The indices of the lights or counters each button affects.
+    pub fn buttons(&self) -> &[Vec<usize>] {
+        &self.buttons
+    }
+
+    pub fn joltage_goal(&self) -> &[usize] {
+        &self.joltage_goal
+    }
+
+    /// The fewest presses that turn on exactly the goal lights (part 1).
+    pub fn minimum_presses_p1(&self) -> Result<usize, SearchError> {
+        let mut queue = std::collections::VecDeque::new();
+        let mut visited = std::collections::HashSet::new();
+
+        let initial_node = P1SearchNode {
+            lights: vec![false; self.goal.len()],
+            presses: 0,
+        };
+        visited.insert(initial_node.lights.clone());
+        queue.push_back(initial_node);
+        
+        while !queue.is_empty() {
+            if visited.len() > SEARCH_LIMIT {
+                return Err(SearchError::TooLong);
+            }
+            trace::gauge("queue length", queue.len() as u64);
+            let node = queue.pop_front().unwrap();
+            trace::count("states expanded", 1);
+
+            if node.is_solved(self) {
+                return Ok(node.presses);
+            }
+
+            for index in 0..self.buttons.len() {
+                let new_machine = node.press(self, index);
+
+                if !visited.contains(&new_machine.lights) {
+                    visited.insert(new_machine.lights.clone());
+                    queue.push_back(new_machine);
+                }
+            }
+        }
+
+        Err(SearchError::Unreachable)
+    }
+
+    /// The fewest presses that raise every counter to its joltage (part 2).
+    ///
+    /// The buttons pressed an odd number of times must leave each counter
+    /// with its joltage's parity. With those pressed once, every button has
+    /// an even number of presses left, which is twice the presses for half
+    /// of what remains. So every set of buttons with the right parity is
+    /// tried, and the search halves the remaining joltages each step.
+    pub fn minimum_presses_p2(&self) -> Result<usize, SearchError> {
+        if self.buttons.len() >= usize::BITS as usize || 1 << self.buttons.len() > SEARCH_LIMIT {
+            return Err(SearchError::TooLong);
+        }
+
+        let mut by_parity : HashMap<Vec<bool>, Vec<Combination>> = HashMap::new();
+        for set in 0..1usize << self.buttons.len() {
+            let mut combination = Combination { raises: vec![0; self.joltage_goal.len()], presses: 0 };
+            for (i, button) in self.buttons.iter().enumerate() {
+                if set & 1 << i != 0 {
+                    combination.presses += 1;
+                    button.iter().for_each(|&counter| combination.raises[counter] += 1);
+                }
+            }
+            by_parity.entry(combination.parity()).or_default().push(combination);
+        }
+
+        let mut fewest = HashMap::new();
+        self.fewest_presses_p2(&by_parity, self.joltage_goal.clone(), &mut fewest)?
+            .ok_or(SearchError::Unreachable)
+    }
+
+    /// The fewest presses that raise the counters by `joltages`, or `None`
+    /// if no presses do, remembered in `fewest` for each `joltages` seen.
+    fn fewest_presses_p2(
+        &self,
+        by_parity : &HashMap<Vec<bool>, Vec<Combination>>,
+        joltages : Vec<usize>,
+        fewest : &mut HashMap<Vec<usize>, Option<usize>>,
+    ) -> Result<Option<usize>, SearchError> {
+        if joltages.iter().all(|&joltage| joltage == 0) {
+            return Ok(Some(0));
+        }
+        if let Some(&presses) = fewest.get(&joltages) {
+            return Ok(presses);
+        }
+        if fewest.len() >= SEARCH_LIMIT {
+            return Err(SearchError::TooLong);
+        }
+        trace::count("states expanded", 1);
+
+        let parity = joltages.iter().map(|joltage| joltage % 2 == 1).collect::<Vec<_>>();
+        let mut best : Option<usize> = None;
+        for combination in by_parity.get(&parity).map_or(&[][..], Vec::as_slice) {
+            if combination.raises.iter().zip(&joltages).any(|(raise, joltage)| raise > joltage) {
+                continue;
+            }
+            let half = joltages.iter()
+                .zip(&combination.raises)
+                .map(|(joltage, raise)| (joltage - raise) / 2)
+                .collect();
+
+            // Never more presses than the joltages sum to, which fits
+            let Some(rest) = self.fewest_presses_p2(by_parity, half, fewest)? else { continue };
+            let presses = combination.presses + 2 * rest;
+            best = Some(best.map_or(presses, |best| best.min(presses)));
+        }
+
+        fewest.insert(joltages, best);
+        Ok(best)
+    }
+}
+
+/// Strips the `open` and `close` delimiters from `part`, a slice of `s`.
+fn bracketed<'a>(s: &str, part: &'a str, open: char, close: char, expected: &str) -> Result<&'a str, ParseError> {
+    part.strip_prefix(open)
+        .and_then(|inner| inner.strip_suffix(close))
+        .ok_or_else(|| ParseError::new(s, part, expected))
+}
+
+impl std::str::FromStr for Machine {
+    type Err = ParseError;
+
+    fn from_str(s: &str) -> Result<Self, ParseError> {
+        let parts = s.trim().split(" ").collect::<Vec<&str>>();
+        let (lights, rest) = parts.split_first()
+            .ok_or_else(|| ParseError::missing(s, "a light diagram"))?;
+        let (joltages, buttons_parts) = rest.split_last()
+            .ok_or_else(|| ParseError::missing(s, "buttons and joltages"))?;
+
+        let goal_lights = bracketed(s, lights, '[', ']', "a light diagram like `[.##.]`")?
+            .char_indices()
+            .map(|(i, ch)| match ch {
+                '#' => Ok(true),
+                '.' => Ok(false),
+                _ => Err(ParseError::new(s, &lights[1 + i..1 + i + ch.len_utf8()], "`#` or `.`")),
+            })
+            .collect::<Result<Vec<bool>, _>>()?;
+
+        let mut buttons = vec![];
+        for part in buttons_parts {
+            let mut wired: Vec<usize> = vec![];
+            for num in bracketed(s, part, '(', ')', "a button like `(0,2)`")?.split(",") {
+                let index: usize = parse::number(s, num, "a light index")?;
+                if index >= goal_lights.len() {
+                    return Err(ParseError::new(s, num, format!("a light index below {}", goal_lights.len())));
+                }
+                // A press raises each of its counters by exactly one
+                if wired.contains(&index) {
+                    return Err(ParseError::new(s, num, "a light index not already on the button"));
+                }
+                wired.push(index);
+            }
+            buttons.push(wired);
+        }
+
+        let joltage_list = bracketed(s, joltages, '{', '}', "joltages like `{3,5,4,7}`")?;
+        let joltages = joltage_list
+            .split(",")
+            .map(|num| parse::number(s, num, "a joltage"))
+            .collect::<Result<Vec<usize>, _>>()?;
+
+        if joltages.len() != goal_lights.len() {
+            return Err(ParseError::new(s, joltage_list, format!("{} joltages", goal_lights.len())));
+        }
+        // Part 2 counts presses up to the sum of the joltages
+        if joltages.iter().try_fold(0usize, |sum, &joltage| sum.checked_add(joltage)).is_none() {
+            return Err(ParseError::new(s, joltage_list, "joltages whose sum fits in 64 bits"));
+        }
+
+        Ok(Machine { 
+            goal: goal_lights, 
+            buttons, 
+            joltage_goal: joltages 
+        })
+    }
+}
+
+/// The fewest presses summed over every machine, in 128 bits so that no
+/// number of machines overflows it.
+fn total_presses(machines: &[Machine], presses: fn(&Machine) -> Result<usize, SearchError>) -> Result<u128, SolveError> {
+    parallel::map(machines, presses)
+        .into_iter()
+        .enumerate()
+        .try_fold(0u128, |total, (i, presses)| match presses {
+            Ok(presses) => Ok(total + presses as u128),
+            Err(err) => Err(SolveError::new(format!("machine {}: {}", i + 1, err))),
+        })
+}
+
+fn machine(machines: &[Machine], args: &[&str]) -> Result<Table, String> {
+    let i = command::position(args, 0, "a machine number", machines.len())?;
+    let machine = &machines[i];
+
+    let lights = machine.goal.iter().map(|&on| if on { '#' } else { '.' }).collect::<String>();
+    let presses = |presses: Result<usize, SearchError>| presses.map_or_else(|err| err.to_string(), |presses| presses.to_string());
+    Ok(Table::new(&["Machine", "Lights", "Buttons", "Part 1 presses", "Part 2 presses"]).row([
+        (i + 1).to_string(),
+        lights,
+        machine.buttons.len().to_string(),
+        presses(machine.minimum_presses_p1()),
+        presses(machine.minimum_presses_p2()),
+    ]))
+}
+
+pub struct Day10;
+
+impl Solution for Day10 {
+    const DAY: u8 = 10;
+
+    type Input = Vec<Machine>;
+
+    fn parse(input: &str) -> Result<Vec<Machine>, ParseError> {
+        parse::lines(input)
+    }
+
+    fn part1(machines: &Vec<Machine>) -> Result<impl Display, SolveError> {
+        total_presses(machines, Machine::minimum_presses_p1)
+    }
+
+    fn part2(machines: &Vec<Machine>) -> Result<impl Display, SolveError> {
+        total_presses(machines, Machine::minimum_presses_p2)
+    }
+
+    /// `size` machines with 3 to 7 lights and 2 to 5 buttons. The light
+    /// diagram and joltages come from actually pressing the buttons, so
+    /// every machine can be solved.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        let machines = (0..size)
+            .map(|_| {
+                let lights = rng.range(3..=7) as usize;
+                let buttons = (0..rng.range(2..=5))
+                    .map(|_| {
+                        let mut wired = (0..lights).filter(|_| rng.chance(0.4)).collect::<Vec<_>>();
+                        if wired.is_empty() {
+                            wired.push(rng.index(lights));
+                        }
+                        wired
+                    })
+                    .collect::<Vec<_>>();
+
+                let mut goal = vec![false; lights];
+                let mut joltages = vec![0; lights];
+                for button in &buttons {
+                    let presses = rng.range(0..=20);
+                    for &light in button {
+                        goal[light] ^= presses % 2 == 1;
+                        joltages[light] += presses;
+                    }
+                }
+
+                let lights = goal.iter().map(|&on| if on { '#' } else { '.' }).collect::<String>();
+                let buttons = buttons
+                    .iter()
+                    .map(|button| format!("({})", button.iter().map(usize::to_string).collect::<Vec<_>>().join(",")))
+                    .collect::<Vec<_>>();
+                let joltages = joltages.iter().map(u64::to_string).collect::<Vec<_>>();
+
+                format!("[{}] {} {{{}}}", lights, buttons.join(" "), joltages.join(","))
+            })
+            .collect::<Vec<_>>();
+
+        Some(machines.join("\n") + "\n")
+    }
+
+    fn commands() -> Vec<Command<Vec<Machine>>> {
+        vec![Command::new("machine", "<n>", "The fewest presses for the input's nth machine, for each part", machine)]
+    }
+}
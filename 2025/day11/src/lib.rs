@@ -0,0 +1,301 @@
+//! Devices wired to each other's inputs, and the paths data can take from
+//! one device to `out`.
+
+use std::{collections::{HashMap, HashSet}, fmt::Display};
+
+use aoc_core::{parse, trace, Command, ParseError, Rng, Solution, SolveError, Table};
+
+/// A device and the devices its outputs lead to, like `aaa: you hhh`.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Node {
+    pub value : String,
+    pub next : Vec<String>
+}
+
+/// How many paths lead from `you` to `out` (part 1), or `None` if there are
+/// more than fit in a `usize`.
+pub fn count_paths(node_by_value : &HashMap<String, &Node>) -> Option<usize> {
+    count_paths_from(node_by_value, "you")
+}
+
+/// How many paths lead from the device `name` to `out`, or `None` if there
+/// are more than fit in a `usize`.
+pub fn count_paths_from(node_by_value : &HashMap<String, &Node>, name : &str) -> Option<usize> {
+    count_paths_between(node_by_value, name, "out")
+}
+
+/// How many paths lead from the device `from` to the device `to`, or `None`
+/// if there are more than fit in a `usize` or the wiring loops.
+pub fn count_paths_between(node_by_value : &HashMap<String, &Node>, from : &str, to : &str) -> Option<usize> {
+    let mut paths : HashMap<&str, usize> = HashMap::new();
+
+    for name in topological_order(node_by_value, from)? {
+        trace::count("nodes visited", 1);
+        let count = if name == to {
+            1
+        } else {
+            // Only `out` has no outputs of its own
+            node_by_value.get(name).map_or(Some(0), |node| {
+                node.next.iter().try_fold(0usize, |sum, next| sum.checked_add(paths[next.as_str()]))
+            })?
+        };
+        paths.insert(name, count);
+    }
+
+    paths.get(from).copied()
+}
+
+/// How many paths lead from `svr` to `out` through both `dac` and `fft`
+/// (part 2), or `None` if there are more than fit in a `usize` or the
+/// wiring loops.
+pub fn count_paths_p2(node_by_value : &HashMap<String, &Node>) -> Option<usize> {
+    // Paths onwards from each device, indexed by which of `dac` (bit 0) and
+    // `fft` (bit 1) the path passed on its way there
+    let mut paths : HashMap<&str, [usize; 4]> = HashMap::new();
+
+    for name in topological_order(node_by_value, "svr")? {
+        trace::count("nodes visited", 1);
+        let mut counts = [0; 4];
+
+        for (visited, count) in counts.iter_mut().enumerate() {
+            let visited = visited | (name == "dac") as usize | ((name == "fft") as usize) << 1;
+            *count = match node_by_value.get(name) {
+                Some(node) => node.next.iter()
+                    .try_fold(0usize, |sum, next| sum.checked_add(paths[next.as_str()][visited]))?,
+                None => (name == "out" && visited == 3) as usize,
+            };
+        }
+        paths.insert(name, counts);
+    }
+
+    paths.get("svr").map(|counts| counts[0])
+}
+
+/// Every device reachable from `from`, `from` included, each after all the
+/// devices its outputs lead to. `None` if a path loops back on itself.
+/// Walks depth-first with a stack of its own, so a long chain of devices
+/// cannot overflow the call stack.
+fn topological_order<'a>(node_by_value : &HashMap<String, &'a Node>, from : &'a str) -> Option<Vec<&'a str>> {
+    let outputs = |name: &str| node_by_value.get(name).map_or(&[][..], |node| node.next.as_slice());
+
+    let mut order = vec![];
+    // Whether each device seen is finished, or still on the current path
+    let mut finished : HashMap<&str, bool> = HashMap::from([(from, false)]);
+    let mut stack = vec![(from, 0)];
+
+    while let Some((name, edge)) = stack.last_mut() {
+        let Some(next) = outputs(name).get(*edge) else {
+            finished.insert(name, true);
+            order.push(*name);
+            stack.pop();
+            continue;
+        };
+        *edge += 1;
+
+        match finished.get(next.as_str()) {
+            None => {
+                finished.insert(next, false);
+                stack.push((next, 0));
+            }
+            Some(false) => return None,
+            Some(true) => {}
+        }
+    }
+
+    Some(order)
+}
+
+/// Makes sure every output leads to a listed device or `out`, that no
+/// device is listed twice and that no path loops back on itself, so every
+/// path count is finite. `lines` are the lines `nodes` were read from.
+fn check_wiring(input: &str, lines: &[&str], nodes: &[Node]) -> Result<(), ParseError> {
+    let mut index_by_value = HashMap::new();
+    for (i, node) in nodes.iter().enumerate() {
+        if node.value == "out" || index_by_value.insert(node.value.as_str(), i).is_some() {
+            return Err(ParseError::new(input, lines[i], "a device not listed before"));
+        }
+    }
+
+    let mut next_indices = vec![];
+    for (node, line) in nodes.iter().zip(lines) {
+        let mut indices = vec![];
+        for next in &node.next {
+            match index_by_value.get(next.as_str()) {
+                Some(&index) => indices.push(index),
+                None if next == "out" => {}
+                None => return Err(ParseError::new(input, line, format!("outputs to listed devices or `out`, not `{}`", next))),
+            }
+        }
+        next_indices.push(indices);
+    }
+
+    // Depth-first, without recursion: 0 is unseen, 1 is on the current
+    // path and 2 is done.
+    let mut state = vec![0u8; nodes.len()];
+    for root in 0..nodes.len() {
+        if state[root] != 0 {
+            continue;
+        }
+        state[root] = 1;
+        let mut stack = vec![(root, 0)];
+
+        while let Some((i, edge)) = stack.last_mut() {
+            let Some(&next) = next_indices[*i].get(*edge) else {
+                state[*i] = 2;
+                stack.pop();
+                continue;
+            };
+            *edge += 1;
+
+            match state[next] {
+                0 => {
+                    state[next] = 1;
+                    stack.push((next, 0));
+                }
+                1 => return Err(ParseError::new(input, lines[next], "a device whose outputs never lead back to it")),
+                _ => {}
+            }
+        }
+    }
+
+    Ok(())
+}
+
+impl std::str::FromStr for Node {
+    type Err = ParseError;
+
+    fn from_str(line: &str) -> Result<Self, ParseError> {
+        let (value, rest) = line.split_once(": ")
+            .ok_or_else(|| ParseError::new(line, line, "a device like `aaa: bbb ccc`"))?;
+
+        if value.is_empty() {
+            return Err(ParseError::new(line, value, "a device name"));
+        }
+
+        let next = rest.split(" ")
+            .map(|s| s.trim().to_string())
+            .collect::<Vec<String>>();
+        Ok(Node { value: value.to_string(), next })
+    }
+}
+
+/// The devices by name, as the path counts take them.
+pub fn index_nodes(nodes: &[Node]) -> HashMap<String, &Node> {
+    nodes.iter()
+        .map(|node| (node.value.clone(), node))
+        .collect()
+}
+
+/// Counts paths with `count`, once `start` is known to be a device.
+fn paths(nodes: &[Node], start: &str, count: fn(&HashMap<String, &Node>) -> Option<usize>) -> Result<usize, SolveError> {
+    let node_by_value = index_nodes(nodes);
+    if !node_by_value.contains_key(start) {
+        return Err(SolveError::new(format!("there is no device named `{}`", start)));
+    }
+
+    count(&node_by_value).ok_or_else(|| SolveError::new("there are more paths than fit in 64 bits"))
+}
+
+/// Reads argument `index` as a listed device or `out`.
+fn device_arg<'a>(node_by_value: &HashMap<String, &Node>, args: &[&'a str], index: usize) -> Result<&'a str, String> {
+    let name = *args.get(index).ok_or("expected a device name")?;
+    if name == "out" || node_by_value.contains_key(name) {
+        Ok(name)
+    } else {
+        Err(format!("there is no device named `{}`", name))
+    }
+}
+
+fn paths_between(nodes: &[Node], args: &[&str]) -> Result<Table, String> {
+    let node_by_value = index_nodes(nodes);
+    let from = device_arg(&node_by_value, args, 0)?;
+    let to = device_arg(&node_by_value, args, 1)?;
+
+    let paths = count_paths_between(&node_by_value, from, to)
+        .map_or("more than fit in 64 bits".to_string(), |paths| paths.to_string());
+    Ok(Table::new(&["From", "To", "Paths"]).row([from.to_string(), to.to_string(), paths]))
+}
+
+fn device(nodes: &[Node], args: &[&str]) -> Result<Table, String> {
+    let name = device_arg(&index_nodes(nodes), args, 0)?;
+    let outputs = nodes.iter()
+        .find(|node| node.value == name)
+        .map_or(vec![], |node| node.next.clone());
+    let inputs = nodes.iter()
+        .filter(|node| node.next.iter().any(|next| next == name))
+        .map(|node| node.value.clone())
+        .collect::<Vec<_>>();
+
+    Ok(Table::new(&["Device", "Outputs", "Inputs"]).row([name.to_string(), outputs.join(" "), inputs.join(" ")]))
+}
+
+pub struct Day11;
+
+impl Solution for Day11 {
+    const DAY: u8 = 11;
+
+    type Input = Vec<Node>;
+
+    fn parse(input: &str) -> Result<Vec<Node>, ParseError> {
+        let nodes = parse::lines(input)?;
+        check_wiring(input, &input.lines().collect::<Vec<_>>(), &nodes)?;
+        Ok(nodes)
+    }
+
+    fn part1(nodes: &Vec<Node>) -> Result<impl Display, SolveError> {
+        paths(nodes, "you", count_paths)
+    }
+
+    fn part2(nodes: &Vec<Node>) -> Result<impl Display, SolveError> {
+        paths(nodes, "svr", count_paths_p2)
+    }
+
+    /// About `size` devices in layers, each wired to one or two devices in
+    /// the next layer and the last layer wired to `out`. The first device of
+    /// each layer is wired to the first of the next, a chain from `svr`
+    /// through `fft` and `dac`, so part 2 always has a path to count.
+    fn generate(rng: &mut Rng, size: usize) -> Option<String> {
+        const RESERVED: [&str; 5] = ["svr", "fft", "dac", "you", "out"];
+
+        let layers = ((size as f64).sqrt() as usize).clamp(5, 16);
+        let width = size.div_ceil(layers).max(2);
+
+        let mut names = HashSet::new();
+        let mut name = || loop {
+            let name = (0..3).map(|_| (b'a' + rng.index(26) as u8) as char).collect::<String>();
+            if !RESERVED.contains(&name.as_str()) && names.insert(name.clone()) {
+                return name;
+            }
+        };
+        let mut grid = (0..layers).map(|_| (0..width).map(|_| name()).collect::<Vec<_>>()).collect::<Vec<_>>();
+        grid[0][0] = "svr".to_string();
+        grid[layers / 3][0] = "fft".to_string();
+        grid[2 * layers / 3][0] = "dac".to_string();
+        grid[layers / 2][1] = "you".to_string();
+
+        let mut lines = vec![];
+        for (layer, devices) in grid.iter().enumerate() {
+            for device in devices {
+                let outputs = match grid.get(layer + 1) {
+                    Some(next) => {
+                        let mut outputs = vec![next[0].clone(), rng.pick(next).clone()];
+                        outputs.dedup();
+                        outputs
+                    }
+                    None => vec!["out".to_string()],
+                };
+                lines.push(format!("{}: {}", device, outputs.join(" ")));
+            }
+        }
+        rng.shuffle(&mut lines);
+
+        Some(lines.join("\n") + "\n")
+    }
+
+    fn commands() -> Vec<Command<Vec<Node>>> {
+        vec![
+            Command::new("paths", "<from> <to>", "How many paths lead from one device to another", paths_between),
+            Command::new("device", "<name>", "The devices a device outputs to and takes input from", device),
+        ]
+    }
+}
@@ -0,0 +1,5 @@
+use std::process::ExitCode;
+
+fn main() -> ExitCode {
+    aoc_core::solution::main(aoc2025_day11::Day11, env!("CARGO_MANIFEST_DIR"))
+}
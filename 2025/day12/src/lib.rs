@@ -0,0 +1,26 @@
+//! Not solved yet; both parts report that they are not implemented.
+
+use std::fmt::Display;
+
+use aoc_core::{ParseError, Solution, SolveError};
+
+pub struct Day12;
+
+impl Solution for Day12 {
+    const DAY: u8 = 12;
+    const PARTS: u8 = 0;
+
+    type Input = String;
+
+    fn parse(input: &str) -> Result<String, ParseError> {
+        Ok(input.to_string())
+    }
+
+    fn part1(_input: &String) -> Result<impl Display, SolveError> {
+        Ok("not implemented")
+    }
+
+    fn part2(_input: &String) -> Result<impl Display, SolveError> {
+        Ok("not implemented")
+    }
+}
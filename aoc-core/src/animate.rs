@@ -0,0 +1,54 @@
+use crate::grid::Grid;
+use crate::vec2::Vec2;
+
+/// Terminal colors a glyph can be drawn in.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum Color {
+    #[default]
+    Default,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    Gray,
+}
+
+/// One character cell of a frame.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Glyph {
+    pub ch: char,
+    pub color: Color,
+}
+
+impl Glyph {
+    pub const BLANK: Glyph = Glyph { ch: ' ', color: Color::Default };
+
+    pub fn new(ch: char, color: Color) -> Self {
+        Self { ch, color }
+    }
+
+    /// Whether the glyph shows nothing, so it can give way to others when a
+    /// frame is shrunk.
+    pub fn is_blank(&self) -> bool {
+        self.ch == ' ' || self.ch == '.'
+    }
+}
+
+/// One step of a simulation, drawn as a grid of glyphs with a caption.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Frame {
+    pub caption: String,
+    pub glyphs: Grid<Glyph>,
+}
+
+impl Frame {
+    /// A frame showing `grid`, with each cell drawn by `glyph`.
+    pub fn from_grid<T>(caption: impl Into<String>, grid: &Grid<T>, glyph: impl Fn(Vec2<usize>, &T) -> Glyph) -> Self {
+        Self {
+            caption: caption.into(),
+            glyphs: grid.map(glyph),
+        }
+    }
+}
@@ -0,0 +1,88 @@
+use std::path::Path;
+
+use serde::Deserialize;
+
+/// File name of the manifest inside each day directory.
+pub const MANIFEST_FILE: &str = "answers.toml";
+
+/// Expected answers for a day, read from `dayNN/answers.toml`.
+///
+/// ```toml
+/// [[input]]
+/// file = "test.txt"
+/// part1 = "3"
+/// part2 = "6"
+/// ```
+#[derive(Debug, Clone, Default, Deserialize)]
+pub struct Manifest {
+    #[serde(default, rename = "input")]
+    pub inputs: Vec<ExpectedAnswers>,
+}
+
+/// The known answers for one input file. A part without a known answer is
+/// left out.
+#[derive(Debug, Clone, Deserialize)]
+pub struct ExpectedAnswers {
+    /// Path of the input, relative to the day directory.
+    pub file: String,
+    pub part1: Option<String>,
+    pub part2: Option<String>,
+}
+
+impl ExpectedAnswers {
+    pub fn part(&self, part: u8) -> Option<&str> {
+        match part {
+            1 => self.part1.as_deref(),
+            2 => self.part2.as_deref(),
+            _ => None,
+        }
+    }
+}
+
+impl Manifest {
+    /// Loads the manifest in `day_dir`. A day without a manifest expects
+    /// nothing.
+    pub fn load(day_dir: &Path) -> Result<Self, String> {
+        let path = day_dir.join(MANIFEST_FILE);
+
+        match std::fs::read_to_string(&path) {
+            Ok(text) => toml::from_str(&text).map_err(|err| format!("invalid {}: {}", path.display(), err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("cannot read {}: {}", path.display(), err)),
+        }
+    }
+
+    /// The expected answer to `part` for the input stored in `file`.
+    pub fn expected(&self, file: &str, part: u8) -> Option<&str> {
+        self.inputs
+            .iter()
+            .find(|input| input.file == file)
+            .and_then(|input| input.part(part))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn parts_are_optional() {
+        let manifest: Manifest = toml::from_str(
+            r#"
+            [[input]]
+            file = "test.txt"
+            part1 = "5"
+
+            [[input]]
+            file = "test2.txt"
+            part2 = "2"
+            "#,
+        )
+        .unwrap();
+
+        assert_eq!(manifest.expected("test.txt", 1), Some("5"));
+        assert_eq!(manifest.expected("test.txt", 2), None);
+        assert_eq!(manifest.expected("test2.txt", 2), Some("2"));
+        assert_eq!(manifest.expected("input.txt", 1), None);
+    }
+}
@@ -0,0 +1,143 @@
+use std::borrow::Borrow;
+use std::fmt;
+use std::str::FromStr;
+
+/// Rows of cells under a header, shown with each column lined up. Cells
+/// that read as numbers are aligned right, anything else left.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Table {
+    pub header: Vec<String>,
+    pub rows: Vec<Vec<String>>,
+}
+
+impl Table {
+    pub fn new(header: &[&str]) -> Self {
+        Self {
+            header: header.iter().map(|cell| cell.to_string()).collect(),
+            rows: Vec::new(),
+        }
+    }
+
+    /// Adds a row; it should have a cell for each column of the header.
+    pub fn row<I: IntoIterator<Item = T>, T: ToString>(mut self, cells: I) -> Self {
+        self.rows.push(cells.into_iter().map(|cell| cell.to_string()).collect());
+        self
+    }
+}
+
+impl fmt::Display for Table {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let widths = (0..self.header.len())
+            .map(|column| {
+                self.rows
+                    .iter()
+                    .chain([&self.header])
+                    .filter_map(|row| row.get(column))
+                    .map(|cell| cell.chars().count())
+                    .max()
+                    .unwrap_or(0)
+            })
+            .collect::<Vec<_>>();
+
+        let line = |row: &[String]| {
+            let cells = row.iter().zip(&widths).map(|(cell, &width)| {
+                if !cell.is_empty() && cell.parse::<f64>().is_ok() {
+                    format!("{:>width$}", cell)
+                } else {
+                    format!("{:<width$}", cell)
+                }
+            });
+            cells.collect::<Vec<_>>().join("  ").trim_end().to_string()
+        };
+
+        writeln!(f, "{}", line(&self.header))?;
+        writeln!(f, "{}", widths.iter().map(|&width| "-".repeat(width)).collect::<Vec<_>>().join("  "))?;
+        for row in &self.rows {
+            writeln!(f, "{}", line(row))?;
+        }
+        Ok(())
+    }
+}
+
+/// How to call a command, for the list `help` prints.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Usage {
+    pub name: &'static str,
+    /// The arguments, like `<from> <to>`; empty for none.
+    pub args: &'static str,
+    pub help: &'static str,
+}
+
+/// What a [`Command`] does with the parsed input and the words typed after
+/// its name.
+pub type Run<I> = Box<dyn Fn(&I, &[&str]) -> Result<Table, String>>;
+
+/// Something a day lets `aoc repl` ask of its parsed input, such as whether
+/// one ingredient is fresh. `run` gets the words after the command's name.
+pub struct Command<I> {
+    pub usage: Usage,
+    pub run: Run<I>,
+}
+
+impl<I: 'static> Command<I> {
+    /// A command that runs `run` on the parsed input, or on what it borrows
+    /// as, like a slice of a `Vec`.
+    pub fn new<V: ?Sized + 'static>(
+        name: &'static str,
+        args: &'static str,
+        help: &'static str,
+        run: fn(&V, &[&str]) -> Result<Table, String>,
+    ) -> Self
+    where
+        I: Borrow<V>,
+    {
+        Self {
+            usage: Usage { name, args, help },
+            run: Box::new(move |input, words| run(input.borrow(), words)),
+        }
+    }
+}
+
+/// Reads argument `index` of `args`, described as `what` (like `an
+/// ingredient ID`) in the error when it is missing or malformed.
+pub fn arg<T: FromStr>(args: &[&str], index: usize, what: &str) -> Result<T, String> {
+    let text = args.get(index).ok_or_else(|| format!("expected {}", what))?;
+    parse(text, what)
+}
+
+/// Reads one word typed as `what`, for commands taking any number of them.
+pub fn parse<T: FromStr>(text: &str, what: &str) -> Result<T, String> {
+    text.parse().map_err(|_| format!("expected {}, not `{}`", what, text))
+}
+
+/// A position in a list given 1-based, as the input's lines are numbered,
+/// turned into an index below `len`.
+pub fn position(args: &[&str], index: usize, what: &str, len: usize) -> Result<usize, String> {
+    let number: usize = arg(args, index, what)?;
+    if number == 0 || number > len {
+        return Err(format!("expected {} from 1 to {}, not {}", what, len, number));
+    }
+    Ok(number - 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn lines_up_columns() {
+        let table = Table::new(&["Bank", "Best"]).row(["1", "98"]).row(["10", "9"]).row(["all", "-"]);
+
+        assert_eq!(table.to_string(), "Bank  Best\n----  ----\n   1    98\n  10     9\nall   -\n");
+    }
+
+    #[test]
+    fn reads_arguments() {
+        assert_eq!(arg::<u64>(&["17"], 0, "an ID"), Ok(17));
+        assert_eq!(arg::<u64>(&[], 0, "an ID"), Err("expected an ID".to_string()));
+        assert_eq!(arg::<u64>(&["x"], 0, "an ID"), Err("expected an ID, not `x`".to_string()));
+        assert_eq!(parse::<u64>("x", "an ID"), Err("expected an ID, not `x`".to_string()));
+        assert_eq!(position(&["3"], 0, "a bank", 3), Ok(2));
+        assert_eq!(position(&["0"], 0, "a bank", 3), Err("expected a bank from 1 to 3, not 0".to_string()));
+    }
+}
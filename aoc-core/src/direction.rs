@@ -0,0 +1,119 @@
+use crate::vec2::Vec2;
+
+/// The four orthogonal directions, in clockwise order starting from `Up`.
+///
+/// Offsets use screen coordinates, so `Up` is `(0, -1)`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir4 {
+    Up,
+    Right,
+    Down,
+    Left,
+}
+
+impl Dir4 {
+    pub const ALL: [Dir4; 4] = [Dir4::Up, Dir4::Right, Dir4::Down, Dir4::Left];
+
+    pub fn offset(self) -> Vec2<isize> {
+        match self {
+            Dir4::Up => Vec2::new(0, -1),
+            Dir4::Right => Vec2::new(1, 0),
+            Dir4::Down => Vec2::new(0, 1),
+            Dir4::Left => Vec2::new(-1, 0),
+        }
+    }
+
+    pub fn turn_right(self) -> Self {
+        Self::ALL[(self as usize + 1) % 4]
+    }
+
+    pub fn turn_left(self) -> Self {
+        Self::ALL[(self as usize + 3) % 4]
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 2) % 4]
+    }
+}
+
+/// The eight king-move directions, in clockwise order starting from `N`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Dir8 {
+    N,
+    NE,
+    E,
+    SE,
+    S,
+    SW,
+    W,
+    NW,
+}
+
+impl Dir8 {
+    pub const ALL: [Dir8; 8] = [
+        Dir8::N,
+        Dir8::NE,
+        Dir8::E,
+        Dir8::SE,
+        Dir8::S,
+        Dir8::SW,
+        Dir8::W,
+        Dir8::NW,
+    ];
+
+    pub fn offset(self) -> Vec2<isize> {
+        match self {
+            Dir8::N => Vec2::new(0, -1),
+            Dir8::NE => Vec2::new(1, -1),
+            Dir8::E => Vec2::new(1, 0),
+            Dir8::SE => Vec2::new(1, 1),
+            Dir8::S => Vec2::new(0, 1),
+            Dir8::SW => Vec2::new(-1, 1),
+            Dir8::W => Vec2::new(-1, 0),
+            Dir8::NW => Vec2::new(-1, -1),
+        }
+    }
+
+    pub fn opposite(self) -> Self {
+        Self::ALL[(self as usize + 4) % 8]
+    }
+}
+
+impl From<Dir4> for Dir8 {
+    fn from(dir: Dir4) -> Self {
+        match dir {
+            Dir4::Up => Dir8::N,
+            Dir4::Right => Dir8::E,
+            Dir4::Down => Dir8::S,
+            Dir4::Left => Dir8::W,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn turning() {
+        assert_eq!(Dir4::Up.turn_right(), Dir4::Right);
+        assert_eq!(Dir4::Up.turn_left(), Dir4::Left);
+        assert_eq!(Dir4::Left.opposite(), Dir4::Right);
+        for dir in Dir4::ALL {
+            assert_eq!(dir.turn_left().turn_right(), dir);
+            assert_eq!(dir.offset(), -dir.opposite().offset());
+        }
+    }
+
+    #[test]
+    fn dir8_offsets_are_distinct_unit_steps() {
+        for dir in Dir8::ALL {
+            let offset = dir.offset();
+            assert_eq!(offset.chebyshev(&Vec2::default()), 1);
+            assert_eq!(offset, -dir.opposite().offset());
+        }
+        for dir in Dir4::ALL {
+            assert_eq!(Dir8::from(dir).offset(), dir.offset());
+        }
+    }
+}
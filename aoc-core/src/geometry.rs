@@ -0,0 +1,114 @@
+use std::ops::Neg;
+
+use crate::scalar::Scalar;
+use crate::vec2::Vec2;
+
+/// A straight segment between two points. Requires a signed scalar type.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Line2<T> {
+    pub start: Vec2<T>,
+    pub end: Vec2<T>,
+}
+
+impl<T: Scalar + Neg<Output = T>> Line2<T> {
+    pub fn new(start: Vec2<T>, end: Vec2<T>) -> Self {
+        Self { start, end }
+    }
+
+    /// Whether the two segments properly cross. Touching endpoints and
+    /// collinear overlaps do not count.
+    pub fn intersects(&self, other: &Self) -> bool {
+        fn ccw<T: Scalar>(a: &Vec2<T>, b: &Vec2<T>, c: &Vec2<T>) -> bool {
+            (c.y - a.y) * (b.x - a.x) > (b.y - a.y) * (c.x - a.x)
+        }
+
+        ccw(&self.start, &other.start, &other.end) != ccw(&self.end, &other.start, &other.end)
+            && ccw(&self.start, &self.end, &other.start) != ccw(&self.start, &self.end, &other.end)
+    }
+
+    /// Whether `point` lies on the segment, endpoints included.
+    pub fn contains(&self, point: Vec2<T>) -> bool {
+        let zero = T::default();
+        let along = self.end - self.start;
+        let offset = point - self.start;
+
+        let cross_product = offset.y * along.x - offset.x * along.y;
+        if cross_product != zero {
+            return false;
+        }
+
+        let dot_product = offset.x * along.x + offset.y * along.y;
+        if dot_product < zero {
+            return false;
+        }
+
+        let squared_length = along.x * along.x + along.y * along.y;
+        dot_product <= squared_length
+    }
+}
+
+/// An axis-aligned rectangle spanned by two corners.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub struct Rect<T> {
+    pub p1: Vec2<T>,
+    pub p2: Vec2<T>,
+}
+
+impl<T: Scalar + Neg<Output = T>> Rect<T> {
+    pub fn new(p1: Vec2<T>, p2: Vec2<T>) -> Self {
+        Self { p1, p2 }
+    }
+
+    /// Whether `point` is inside or on the border. Assumes `p1` is the
+    /// top-left corner.
+    pub fn contains(&self, point: Vec2<T>) -> bool {
+        point.x >= self.p1.x && point.x <= self.p2.x && point.y >= self.p1.y && point.y <= self.p2.y
+    }
+
+    pub fn edges(&self) -> [Line2<T>; 4] {
+        let top_right = Vec2::new(self.p2.x, self.p1.y);
+        let bottom_left = Vec2::new(self.p1.x, self.p2.y);
+        [
+            Line2::new(self.p1, top_right),
+            Line2::new(top_right, self.p2),
+            Line2::new(self.p2, bottom_left),
+            Line2::new(bottom_left, self.p1),
+        ]
+    }
+
+    /// Whether any edge of the rectangle properly crosses `line`.
+    pub fn intersects_line(&self, line: &Line2<T>) -> bool {
+        self.edges().iter().any(|edge| edge.intersects(line))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn crossing_segments_intersect() {
+        let a = Line2::new(Vec2::new(0, 0), Vec2::new(4, 4));
+        let b = Line2::new(Vec2::new(0, 4), Vec2::new(4, 0));
+        let c = Line2::new(Vec2::new(5, 0), Vec2::new(5, 4));
+        assert!(a.intersects(&b));
+        assert!(!a.intersects(&c));
+    }
+
+    #[test]
+    fn contains_points_on_segment() {
+        let line = Line2::new(Vec2::new(1i64, 1), Vec2::new(5, 1));
+        assert!(line.contains(Vec2::new(1, 1)));
+        assert!(line.contains(Vec2::new(3, 1)));
+        assert!(!line.contains(Vec2::new(6, 1)));
+        assert!(!line.contains(Vec2::new(3, 2)));
+    }
+
+    #[test]
+    fn rect_edges_cross_lines() {
+        let rect = Rect::new(Vec2::new(0, 0), Vec2::new(4, 4));
+        assert!(rect.contains(Vec2::new(4, 2)));
+        assert!(rect.intersects_line(&Line2::new(Vec2::new(2, -1), Vec2::new(2, 2))));
+        assert!(!rect.intersects_line(&Line2::new(Vec2::new(1, 1), Vec2::new(3, 3))));
+    }
+}
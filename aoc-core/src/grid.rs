@@ -0,0 +1,366 @@
+use std::fmt;
+use std::ops::{Index, IndexMut};
+
+use crate::direction::{Dir4, Dir8};
+use crate::parse::ParseError;
+use crate::vec2::Vec2;
+
+/// Indexing of row-major nested grids by position.
+pub trait GridIndex<T> {
+    fn at(&self, pos: Vec2<usize>) -> &T;
+    fn at_mut(&mut self, pos: Vec2<usize>) -> &mut T;
+    /// Bounds-checked lookup.
+    fn get_at(&self, pos: Vec2<usize>) -> Option<&T>;
+
+    fn in_bounds(&self, pos: Vec2<usize>) -> bool {
+        self.get_at(pos).is_some()
+    }
+}
+
+impl<T> GridIndex<T> for [Vec<T>] {
+    fn at(&self, pos: Vec2<usize>) -> &T {
+        &self[pos.y][pos.x]
+    }
+
+    fn at_mut(&mut self, pos: Vec2<usize>) -> &mut T {
+        &mut self[pos.y][pos.x]
+    }
+
+    fn get_at(&self, pos: Vec2<usize>) -> Option<&T> {
+        self.get(pos.y)?.get(pos.x)
+    }
+}
+
+impl<T> GridIndex<T> for Vec<Vec<T>> {
+    fn at(&self, pos: Vec2<usize>) -> &T {
+        self.as_slice().at(pos)
+    }
+
+    fn at_mut(&mut self, pos: Vec2<usize>) -> &mut T {
+        self.as_mut_slice().at_mut(pos)
+    }
+
+    fn get_at(&self, pos: Vec2<usize>) -> Option<&T> {
+        self.as_slice().get_at(pos)
+    }
+}
+
+/// A dense rectangular grid stored row-major in a single `Vec`.
+///
+/// Positions are `Vec2 { x: column, y: row }` with the origin at the top
+/// left, matching the layout of the puzzle text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Grid<T> {
+    width: usize,
+    height: usize,
+    cells: Vec<T>,
+}
+
+impl<T> Grid<T> {
+    /// A `width` by `height` grid with every cell set to `fill`.
+    pub fn new(width: usize, height: usize, fill: T) -> Self
+    where
+        T: Clone,
+    {
+        Self {
+            width,
+            height,
+            cells: vec![fill; width * height],
+        }
+    }
+
+    /// Parses one row per line of `input`, mapping each character to a cell
+    /// with `cell`. Characters it rejects and rows of the wrong width are
+    /// errors pointing at the offending text; `expected` describes the
+    /// accepted characters, e.g. "`#` or `.`".
+    pub fn parse(input: &str, expected: &str, cell: impl Fn(char) -> Option<T>) -> Result<Self, ParseError> {
+        let mut width = None;
+        let mut height = 0;
+        let mut cells = vec![];
+
+        for line in input.lines() {
+            let before = cells.len();
+            for (i, ch) in line.char_indices() {
+                let value = cell(ch).ok_or_else(|| ParseError::new(input, &line[i..i + ch.len_utf8()], expected))?;
+                cells.push(value);
+            }
+
+            let row_width = cells.len() - before;
+            match width {
+                None => width = Some(row_width),
+                Some(width) if width != row_width => {
+                    return Err(ParseError::new(input, line, format!("a row of {} cells", width)))
+                }
+                Some(_) => {}
+            }
+            height += 1;
+        }
+
+        Ok(Self {
+            width: width.unwrap_or(0),
+            height,
+            cells,
+        })
+    }
+
+    pub fn width(&self) -> usize {
+        self.width
+    }
+
+    pub fn height(&self) -> usize {
+        self.height
+    }
+
+    pub fn in_bounds(&self, pos: Vec2<usize>) -> bool {
+        pos.x < self.width && pos.y < self.height
+    }
+
+    fn index_of(&self, pos: Vec2<usize>) -> Option<usize> {
+        self.in_bounds(pos).then(|| pos.y * self.width + pos.x)
+    }
+
+    /// Bounds-checked lookup.
+    pub fn get(&self, pos: Vec2<usize>) -> Option<&T> {
+        self.index_of(pos).map(|i| &self.cells[i])
+    }
+
+    pub fn get_mut(&mut self, pos: Vec2<usize>) -> Option<&mut T> {
+        self.index_of(pos).map(|i| &mut self.cells[i])
+    }
+
+    /// Every position, row by row.
+    pub fn positions(&self) -> impl Iterator<Item = Vec2<usize>> {
+        let width = self.width;
+        (0..self.height).flat_map(move |y| (0..width).map(move |x| Vec2::new(x, y)))
+    }
+
+    /// Every cell with its position, row by row.
+    pub fn iter(&self) -> impl Iterator<Item = (Vec2<usize>, &T)> {
+        self.positions().zip(&self.cells)
+    }
+
+    /// A grid of the same shape with every cell replaced by `f` of its
+    /// position and value.
+    pub fn map<U>(&self, f: impl Fn(Vec2<usize>, &T) -> U) -> Grid<U> {
+        Grid {
+            width: self.width,
+            height: self.height,
+            cells: self.iter().map(|(pos, cell)| f(pos, cell)).collect(),
+        }
+    }
+
+    /// The position of the first cell, row by row, that matches `predicate`.
+    pub fn find(&self, predicate: impl Fn(&T) -> bool) -> Option<Vec2<usize>> {
+        self.iter().find(|(_, cell)| predicate(cell)).map(|(pos, _)| pos)
+    }
+
+    fn neighbors(&self, pos: Vec2<usize>, offsets: impl Iterator<Item = Vec2<isize>>) -> impl Iterator<Item = Vec2<usize>> {
+        let (width, height) = (self.width, self.height);
+        offsets
+            .filter_map(move |offset| pos.checked_offset(offset))
+            .filter(move |pos| pos.x < width && pos.y < height)
+    }
+
+    /// The orthogonal neighbors of `pos` that lie inside the grid.
+    pub fn neighbors4(&self, pos: Vec2<usize>) -> impl Iterator<Item = Vec2<usize>> {
+        self.neighbors(pos, Dir4::ALL.into_iter().map(Dir4::offset))
+    }
+
+    /// The orthogonal and diagonal neighbors of `pos` that lie inside the
+    /// grid.
+    pub fn neighbors8(&self, pos: Vec2<usize>) -> impl Iterator<Item = Vec2<usize>> {
+        self.neighbors(pos, Dir8::ALL.into_iter().map(Dir8::offset))
+    }
+
+    pub fn row(&self, y: usize) -> &[T] {
+        &self.cells[y * self.width..(y + 1) * self.width]
+    }
+
+    pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+        (0..self.height).map(|y| self.row(y))
+    }
+
+    pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+        assert!(x < self.width, "column {} out of bounds for width {}", x, self.width);
+        self.cells.iter().skip(x).step_by(self.width)
+    }
+
+    pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+        (0..self.width).map(|x| self.column(x))
+    }
+
+    /// Builds a `width` by `height` grid by taking each cell from this one
+    /// at the position `source` maps it to.
+    fn remap(&self, width: usize, height: usize, source: impl Fn(usize, usize) -> Vec2<usize>) -> Self
+    where
+        T: Clone,
+    {
+        let cells = (0..height)
+            .flat_map(|y| (0..width).map(move |x| (x, y)))
+            .map(|(x, y)| self[source(x, y)].clone())
+            .collect();
+
+        Self { width, height, cells }
+    }
+
+    /// Swaps rows and columns.
+    pub fn transpose(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| Vec2::new(y, x))
+    }
+
+    /// Rotates a quarter turn clockwise.
+    pub fn rotate_right(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| Vec2::new(y, self.height - 1 - x))
+    }
+
+    /// Rotates a quarter turn counterclockwise.
+    pub fn rotate_left(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.height, self.width, |x, y| Vec2::new(self.width - 1 - y, x))
+    }
+
+    /// Mirrors left to right.
+    pub fn flip_horizontal(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.width, self.height, |x, y| Vec2::new(self.width - 1 - x, y))
+    }
+
+    /// Mirrors top to bottom.
+    pub fn flip_vertical(&self) -> Self
+    where
+        T: Clone,
+    {
+        self.remap(self.width, self.height, |x, y| Vec2::new(x, self.height - 1 - y))
+    }
+}
+
+impl<T> Index<Vec2<usize>> for Grid<T> {
+    type Output = T;
+
+    fn index(&self, pos: Vec2<usize>) -> &T {
+        self.get(pos)
+            .unwrap_or_else(|| panic!("{:?} out of bounds for a {}x{} grid", pos, self.width, self.height))
+    }
+}
+
+impl<T> IndexMut<Vec2<usize>> for Grid<T> {
+    fn index_mut(&mut self, pos: Vec2<usize>) -> &mut T {
+        let (width, height) = (self.width, self.height);
+        self.get_mut(pos)
+            .unwrap_or_else(|| panic!("{:?} out of bounds for a {}x{} grid", pos, width, height))
+    }
+}
+
+impl<T> GridIndex<T> for Grid<T> {
+    fn at(&self, pos: Vec2<usize>) -> &T {
+        &self[pos]
+    }
+
+    fn at_mut(&mut self, pos: Vec2<usize>) -> &mut T {
+        &mut self[pos]
+    }
+
+    fn get_at(&self, pos: Vec2<usize>) -> Option<&T> {
+        self.get(pos)
+    }
+}
+
+/// Writes one line per row, each ending in a newline as input files do, so
+/// a grid of cells whose `Display` is the character they were parsed from
+/// prints back to its input.
+impl<T: fmt::Display> fmt::Display for Grid<T> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for row in self.rows() {
+            for cell in row {
+                write!(f, "{}", cell)?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn indexes_rows_by_y() {
+        let mut grid = vec![vec![1, 2, 3], vec![4, 5, 6]];
+        assert_eq!(*grid.at(Vec2::new(2, 1)), 6);
+        *grid.at_mut(Vec2::new(0, 1)) = 9;
+        assert_eq!(grid[1][0], 9);
+        assert_eq!(grid.get_at(Vec2::new(3, 0)), None);
+        assert!(!grid.in_bounds(Vec2::new(0, 2)));
+    }
+
+    fn sample() -> Grid<char> {
+        Grid::parse("abc\ndef", "a letter", |ch| ch.is_ascii_lowercase().then_some(ch)).unwrap()
+    }
+
+    fn text(grid: &Grid<char>) -> String {
+        grid.to_string()
+    }
+
+    #[test]
+    fn parses_and_prints_back() {
+        let grid = sample();
+        assert_eq!((grid.width(), grid.height()), (3, 2));
+        assert_eq!(grid[Vec2::new(2, 1)], 'f');
+        assert_eq!(grid.get(Vec2::new(3, 0)), None);
+        assert_eq!(text(&grid), "abc\ndef\n");
+
+        let input = "abc\ndef\n";
+        assert_eq!(text(&Grid::parse(input, "a cell", Some).unwrap()), input);
+    }
+
+    #[test]
+    fn parse_errors_point_at_the_cell() {
+        let err = Grid::parse("ab\naX", "a letter", |ch| ch.is_ascii_lowercase().then_some(ch)).unwrap_err();
+        assert_eq!((err.line, err.column, err.snippet.as_str()), (2, 2, "X"));
+
+        let err = Grid::parse("ab\nabc", "a letter", Some).unwrap_err();
+        assert_eq!((err.line, err.column), (2, 1));
+        assert_eq!(err.expected, "a row of 2 cells");
+    }
+
+    #[test]
+    fn neighbors_stay_in_bounds() {
+        let grid = sample();
+        let mut corner = grid.neighbors8(Vec2::new(0, 0)).collect::<Vec<_>>();
+        corner.sort_by_key(|pos| (pos.y, pos.x));
+        assert_eq!(corner, vec![Vec2::new(1, 0), Vec2::new(0, 1), Vec2::new(1, 1)]);
+        assert_eq!(grid.neighbors4(Vec2::new(1, 1)).count(), 3);
+    }
+
+    #[test]
+    fn rows_and_columns() {
+        let grid = sample();
+        assert_eq!(grid.row(1), ['d', 'e', 'f']);
+        assert_eq!(grid.column(1).collect::<String>(), "be");
+        assert_eq!(grid.columns().count(), 3);
+        assert_eq!(grid.find(|&ch| ch == 'e'), Some(Vec2::new(1, 1)));
+        assert_eq!(grid.map(|pos, _| pos.x + pos.y).row(1), [1, 2, 3]);
+    }
+
+    #[test]
+    fn transforms() {
+        let grid = sample();
+        assert_eq!(text(&grid.transpose()), "ad\nbe\ncf\n");
+        assert_eq!(text(&grid.rotate_right()), "da\neb\nfc\n");
+        assert_eq!(text(&grid.rotate_left()), "cf\nbe\nad\n");
+        assert_eq!(text(&grid.flip_horizontal()), "cba\nfed\n");
+        assert_eq!(text(&grid.flip_vertical()), "def\nabc\n");
+        assert_eq!(grid.rotate_right().rotate_left(), grid);
+    }
+}
@@ -0,0 +1,147 @@
+use std::fmt;
+use std::io::Read;
+use std::path::{Path, PathBuf};
+
+use clap::{Args, Parser};
+
+/// Command line flags selecting which input a day runs on.
+#[derive(Args, Debug, Clone, Default)]
+pub struct InputArgs {
+    /// Read the puzzle input from this file, or `-` for stdin
+    #[arg(short, long, value_name = "PATH", conflicts_with = "example")]
+    pub input: Option<PathBuf>,
+
+    /// Use the day's example input: testN.txt, or test.txt when N is 1 or omitted
+    #[arg(short, long, value_name = "N", num_args = 0..=1, default_missing_value = "1")]
+    pub example: Option<u32>,
+}
+
+impl InputArgs {
+    pub fn source(&self) -> InputSource {
+        match (&self.input, self.example) {
+            (Some(path), _) if path.as_os_str() == "-" => InputSource::Stdin,
+            (Some(path), _) => InputSource::Path(path.clone()),
+            (None, Some(n)) => InputSource::Example(n),
+            (None, None) => InputSource::Puzzle,
+        }
+    }
+}
+
+/// Where a day's input comes from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputSource {
+    /// The day's `input.txt`.
+    Puzzle,
+    /// The day's `test.txt` for example 1, `testN.txt` otherwise.
+    Example(u32),
+    Path(PathBuf),
+    Stdin,
+}
+
+impl InputSource {
+    /// The file this source reads, relative to `day_dir` where applicable.
+    /// `None` for stdin.
+    pub fn path(&self, day_dir: &Path) -> Option<PathBuf> {
+        match self {
+            InputSource::Puzzle => Some(day_dir.join("input.txt")),
+            InputSource::Example(1) => Some(day_dir.join("test.txt")),
+            InputSource::Example(n) => Some(day_dir.join(format!("test{}.txt", n))),
+            InputSource::Path(path) => Some(path.clone()),
+            InputSource::Stdin => None,
+        }
+    }
+
+    pub fn load(&self, day_dir: &Path) -> Result<Input, InputError> {
+        let Some(path) = self.path(day_dir) else {
+            let mut text = String::new();
+            std::io::stdin()
+                .read_to_string(&mut text)
+                .map_err(|err| InputError::Stdin(err.to_string()))?;
+            return Ok(Input { text, origin: "<stdin>".to_string() });
+        };
+
+        match std::fs::read_to_string(&path) {
+            Ok(text) => Ok(Input { text, origin: path.display().to_string() }),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Err(InputError::Missing {
+                path,
+                source: self.clone(),
+            }),
+            Err(err) => Err(InputError::Unreadable { path, reason: err.to_string() }),
+        }
+    }
+}
+
+/// Puzzle input loaded at runtime.
+#[derive(Debug, Clone)]
+pub struct Input {
+    pub text: String,
+    /// The file it was read from, or `<stdin>`.
+    pub origin: String,
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum InputError {
+    Missing { path: PathBuf, source: InputSource },
+    Unreadable { path: PathBuf, reason: String },
+    Stdin(String),
+}
+
+impl fmt::Display for InputError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            InputError::Missing { path, source } => {
+                write!(f, "input file {} does not exist", path.display())?;
+                match source {
+                    InputSource::Puzzle => write!(f, " (save your puzzle input there, or pass --input <PATH>)"),
+                    InputSource::Example(n) => write!(f, " (this day has no example {})", n),
+                    _ => Ok(()),
+                }
+            }
+            InputError::Unreadable { path, reason } => write!(f, "cannot read {}: {}", path.display(), reason),
+            InputError::Stdin(reason) => write!(f, "cannot read stdin: {}", reason),
+        }
+    }
+}
+
+impl std::error::Error for InputError {}
+
+/// Command line of a day's own binary.
+#[derive(Parser, Debug, Clone)]
+pub struct DayArgs {
+    #[command(flatten)]
+    pub input: InputArgs,
+
+    /// Save a picture of the input to this SVG file, if the day draws one
+    #[arg(long, value_name = "FILE")]
+    pub render: Option<PathBuf>,
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn example_numbers_map_to_test_files() {
+        let dir = Path::new("day11");
+        assert_eq!(InputSource::Puzzle.path(dir), Some(dir.join("input.txt")));
+        assert_eq!(InputSource::Example(1).path(dir), Some(dir.join("test.txt")));
+        assert_eq!(InputSource::Example(2).path(dir), Some(dir.join("test2.txt")));
+        assert_eq!(InputSource::Stdin.path(dir), None);
+    }
+
+    #[test]
+    fn dash_selects_stdin() {
+        let args = InputArgs { input: Some(PathBuf::from("-")), example: None };
+        assert_eq!(args.source(), InputSource::Stdin);
+    }
+
+    #[test]
+    fn missing_file_is_reported_with_its_path() {
+        let err = InputSource::Example(7).load(Path::new("no-such-day")).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            format!("input file {} does not exist (this day has no example 7)", Path::new("no-such-day").join("test7.txt").display()),
+        );
+    }
+}
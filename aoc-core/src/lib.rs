@@ -0,0 +1,36 @@
+//! Shared building blocks for the Advent of Code solutions.
+//!
+//! Every day crate depends on this library instead of carrying its own copy
+//! of position and grid helpers, and implements [`Solution`] so the `aoc`
+//! runner can drive it.
+
+pub mod animate;
+pub mod answers;
+pub mod command;
+pub mod direction;
+pub mod geometry;
+pub mod grid;
+pub mod input;
+pub mod parallel;
+pub mod parse;
+pub mod random;
+pub mod render;
+pub mod scalar;
+pub mod solution;
+pub mod trace;
+pub mod vec2;
+pub mod vec3;
+
+pub use animate::{Color, Frame, Glyph};
+pub use command::{Command, Table, Usage};
+pub use direction::{Dir4, Dir8};
+pub use geometry::{Line2, Rect};
+pub use grid::{Grid, GridIndex};
+pub use input::{Input, InputArgs, InputError, InputSource};
+pub use parse::ParseError;
+pub use random::Rng;
+pub use render::{Scene, Style};
+pub use scalar::Scalar;
+pub use solution::{Puzzle, Solution, SolveError};
+pub use vec2::Vec2;
+pub use vec3::Vec3;
@@ -0,0 +1,40 @@
+//! Per-item work that spreads over every core when the `parallel` feature
+//! is on, and runs in order on the calling thread when it is off. Results
+//! come back in the order of the items either way, so answers and output do
+//! not depend on the feature.
+
+use std::iter::Sum;
+
+/// `f` applied to each of `items`, in order.
+#[cfg(feature = "parallel")]
+pub fn map<T: Sync, U: Send>(items: &[T], f: impl Fn(&T) -> U + Sync + Send) -> Vec<U> {
+    use rayon::prelude::*;
+
+    // Workers count towards the span that handed out the work.
+    let context = crate::trace::current();
+    items.par_iter().map(|item| crate::trace::within(&context, || f(item))).collect()
+}
+
+/// `f` applied to each of `items`, in order.
+#[cfg(not(feature = "parallel"))]
+pub fn map<T: Sync, U: Send>(items: &[T], f: impl Fn(&T) -> U + Sync + Send) -> Vec<U> {
+    items.iter().map(f).collect()
+}
+
+/// The sum of `f` over `items`.
+pub fn sum<T: Sync, S: Send + Sum>(items: &[T], f: impl Fn(&T) -> S + Sync + Send) -> S {
+    map(items, f).into_iter().sum()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn keeps_item_order() {
+        let items = (0..1000).collect::<Vec<u64>>();
+
+        assert_eq!(map(&items, |n| n * 2), items.iter().map(|n| n * 2).collect::<Vec<_>>());
+        assert_eq!(sum(&items, |&n| n), 499_500);
+    }
+}
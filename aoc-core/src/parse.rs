@@ -0,0 +1,160 @@
+use std::fmt;
+use std::str::FromStr;
+
+/// An input that could not be parsed, pointing at the offending text.
+///
+/// Positions are 1-based and relative to the text the failing parser was
+/// given. Parsers that hand a slice of their input to another parser move
+/// the error back into their own coordinates with [`ParseError::rebase`].
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParseError {
+    pub line: usize,
+    pub column: usize,
+    /// The text that was rejected. Empty when something is missing.
+    pub snippet: String,
+    /// What the parser was looking for, e.g. "a number".
+    pub expected: String,
+}
+
+/// Line and column at which `inner` starts, if it is a slice of `outer`.
+fn position(outer: &str, inner: &str) -> Option<(usize, usize)> {
+    let start = (inner.as_ptr() as usize).checked_sub(outer.as_ptr() as usize)?;
+    let before = outer.get(..start)?;
+    let line = before.matches('\n').count() + 1;
+    let line_start = before.rfind('\n').map_or(0, |i| i + 1);
+    let column = before[line_start..].chars().count() + 1;
+    Some((line, column))
+}
+
+impl ParseError {
+    /// An error about `snippet`, which should be a slice of `source`; its
+    /// position is taken from where it sits in `source`. A snippet from
+    /// elsewhere is reported at the start of `source`.
+    pub fn new(source: &str, snippet: &str, expected: impl Into<String>) -> Self {
+        let (line, column) = position(source, snippet).unwrap_or((1, 1));
+
+        Self {
+            line,
+            column,
+            snippet: snippet.to_string(),
+            expected: expected.into(),
+        }
+    }
+
+    /// An error about something missing at the end of `source`.
+    pub fn missing(source: &str, expected: impl Into<String>) -> Self {
+        Self::new(source, &source[source.len()..], expected)
+    }
+
+    /// Moves an error reported against `inner` into the coordinates of
+    /// `outer`, where `inner` is a slice of `outer`.
+    pub fn rebase(mut self, outer: &str, inner: &str) -> Self {
+        if let Some((line, column)) = position(outer, inner) {
+            if self.line == 1 {
+                self.column += column - 1;
+            }
+            self.line += line - 1;
+        }
+        self
+    }
+
+    /// Renders the error as a compiler-style diagnostic against the full
+    /// `source`, which was read from `origin`.
+    pub fn render(&self, origin: &str, source: &str) -> String {
+        let source_line = source.lines().nth(self.line - 1).unwrap_or("");
+        let number = self.line.to_string();
+        let gutter = " ".repeat(number.len());
+        let underline = "^".repeat(self.snippet.chars().count().max(1));
+
+        format!(
+            "error: {}\n{}--> {}:{}:{}\n{} |\n{} | {}\n{} | {}{}\n",
+            self,
+            gutter,
+            origin,
+            self.line,
+            self.column,
+            gutter,
+            number,
+            source_line,
+            gutter,
+            " ".repeat(self.column - 1),
+            underline,
+        )
+    }
+}
+
+impl fmt::Display for ParseError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self.snippet.as_str() {
+            "" => write!(f, "expected {}, found nothing", self.expected),
+            snippet => write!(f, "expected {}, found `{}`", self.expected, snippet),
+        }
+    }
+}
+
+impl std::error::Error for ParseError {}
+
+/// Parses `part`, a slice of `source`, as a number.
+pub fn number<T: FromStr>(source: &str, part: &str, expected: &str) -> Result<T, ParseError> {
+    part.parse().map_err(|_| ParseError::new(source, part, expected))
+}
+
+/// Parses each line of `input` with `T::from_str`, reporting errors at their
+/// position in `input`.
+pub fn lines<T: FromStr<Err = ParseError>>(input: &str) -> Result<Vec<T>, ParseError> {
+    input
+        .lines()
+        .map(|line| line.parse().map_err(|err: ParseError| err.rebase(input, line)))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[derive(Debug, PartialEq)]
+    struct Pair(u32, u32);
+
+    impl FromStr for Pair {
+        type Err = ParseError;
+
+        fn from_str(s: &str) -> Result<Self, ParseError> {
+            let (a, b) = s.split_once('-').ok_or_else(|| ParseError::missing(s, "`-`"))?;
+            Ok(Pair(number(s, a, "a number")?, number(s, b, "a number")?))
+        }
+    }
+
+    #[test]
+    fn parses_every_line() {
+        assert_eq!(lines::<Pair>("1-2\n3-4").unwrap(), vec![Pair(1, 2), Pair(3, 4)]);
+    }
+
+    #[test]
+    fn errors_point_into_the_whole_input() {
+        let input = "1-2\n3-4\n5-x6\n";
+        let err = lines::<Pair>(input).unwrap_err();
+
+        assert_eq!((err.line, err.column), (3, 3));
+        assert_eq!(err.snippet, "x6");
+        assert_eq!(err.to_string(), "expected a number, found `x6`");
+    }
+
+    #[test]
+    fn missing_text_points_past_the_end() {
+        let err = lines::<Pair>("1-2\n34").unwrap_err();
+
+        assert_eq!((err.line, err.column), (2, 3));
+        assert_eq!(err.to_string(), "expected `-`, found nothing");
+    }
+
+    #[test]
+    fn renders_like_a_compiler() {
+        let input = "1-2\n5-x6";
+        let err = lines::<Pair>(input).unwrap_err();
+
+        assert_eq!(
+            err.render("test.txt", input),
+            "error: expected a number, found `x6`\n --> test.txt:2:3\n  |\n2 | 5-x6\n  |   ^^\n",
+        );
+    }
+}
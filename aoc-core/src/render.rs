@@ -0,0 +1,360 @@
+use std::fmt::Write;
+use std::path::Path;
+
+use crate::geometry::{Line2, Rect};
+use crate::grid::Grid;
+use crate::scalar::Scalar;
+use crate::vec2::Vec2;
+
+/// Width in pixels of the drawing area; the height follows the aspect ratio
+/// of the scene.
+const DRAWING_SIZE: f64 = 1000.0;
+const MARGIN: f64 = 20.0;
+const LEGEND_WIDTH: f64 = 220.0;
+const TITLE_HEIGHT: f64 = 30.0;
+
+/// How a shape is drawn. Widths and point radii are in pixels of the final
+/// picture, whatever the scale of the puzzle coordinates. Styles with a
+/// label get an entry in the legend.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Style {
+    pub stroke: Option<String>,
+    pub fill: Option<String>,
+    pub width: f64,
+    pub label: Option<String>,
+}
+
+impl Style {
+    /// Outlines in `color`.
+    pub fn stroke(color: &str) -> Self {
+        Self {
+            stroke: Some(color.to_string()),
+            fill: None,
+            width: 1.0,
+            label: None,
+        }
+    }
+
+    /// Filled with `color`, without an outline.
+    pub fn fill(color: &str) -> Self {
+        Self {
+            stroke: None,
+            fill: Some(color.to_string()),
+            width: 1.0,
+            label: None,
+        }
+    }
+
+    pub fn width(mut self, width: f64) -> Self {
+        self.width = width;
+        self
+    }
+
+    pub fn label(mut self, label: &str) -> Self {
+        self.label = Some(label.to_string());
+        self
+    }
+}
+
+#[derive(Debug, Clone)]
+enum Shape {
+    Polyline { points: Vec<Vec2<f64>>, closed: bool },
+    Rect { min: Vec2<f64>, max: Vec2<f64> },
+    Point(Vec2<f64>),
+}
+
+impl Shape {
+    fn points(&self) -> Vec<Vec2<f64>> {
+        match self {
+            Shape::Polyline { points, .. } => points.clone(),
+            Shape::Rect { min, max } => vec![*min, *max],
+            Shape::Point(at) => vec![*at],
+        }
+    }
+}
+
+fn to_f64<T: Scalar>(point: Vec2<T>) -> Vec2<f64> {
+    Vec2::new(point.x.to_f64(), point.y.to_f64())
+}
+
+/// Escapes text for use inside SVG markup.
+fn escape(text: &str) -> String {
+    text.replace('&', "&amp;")
+        .replace('<', "&lt;")
+        .replace('>', "&gt;")
+        .replace('"', "&quot;")
+}
+
+/// A picture of a puzzle, built from shapes in puzzle coordinates and
+/// written out as SVG.
+///
+/// Coordinates grow right and down like the puzzle text. The picture is
+/// fitted to the bounds of everything drawn, so shapes can use whatever
+/// scale the puzzle uses.
+#[derive(Debug, Clone, Default)]
+pub struct Scene {
+    title: Option<String>,
+    shapes: Vec<(Shape, Style)>,
+    legend: Vec<Style>,
+}
+
+impl Scene {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn set_title(&mut self, title: &str) {
+        self.title = Some(title.to_string());
+    }
+
+    fn add(&mut self, shape: Shape, style: Style) {
+        self.legend_entry(&style);
+        self.shapes.push((shape, style));
+    }
+
+    fn legend_entry(&mut self, style: &Style) {
+        let Some(label) = &style.label else { return };
+        if !self.legend.iter().any(|entry| entry.label.as_ref() == Some(label)) {
+            self.legend.push(style.clone());
+        }
+    }
+
+    /// Adds a legend entry for shapes drawn without a label, such as grid
+    /// cells.
+    pub fn legend(&mut self, style: Style) {
+        self.legend_entry(&style);
+    }
+
+    /// Connected line segments through `points`.
+    pub fn polyline<T: Scalar>(&mut self, points: impl IntoIterator<Item = Vec2<T>>, style: Style) {
+        let points = points.into_iter().map(to_f64).collect();
+        self.add(Shape::Polyline { points, closed: false }, style);
+    }
+
+    /// A closed outline through `points`.
+    pub fn polygon<T: Scalar>(&mut self, points: impl IntoIterator<Item = Vec2<T>>, style: Style) {
+        let points = points.into_iter().map(to_f64).collect();
+        self.add(Shape::Polyline { points, closed: true }, style);
+    }
+
+    pub fn line<T: Scalar>(&mut self, line: &Line2<T>, style: Style) {
+        self.polyline([line.start, line.end], style);
+    }
+
+    /// The rectangle with opposite corners `rect.p1` and `rect.p2`.
+    pub fn rect<T: Scalar>(&mut self, rect: &Rect<T>, style: Style) {
+        let (p1, p2) = (to_f64(rect.p1), to_f64(rect.p2));
+        let min = Vec2::new(p1.x.min(p2.x), p1.y.min(p2.y));
+        let max = Vec2::new(p1.x.max(p2.x), p1.y.max(p2.y));
+        self.add(Shape::Rect { min, max }, style);
+    }
+
+    /// A dot at `at`, `style.width` pixels in radius.
+    pub fn point<T: Scalar>(&mut self, at: Vec2<T>, style: Style) {
+        self.add(Shape::Point(to_f64(at)), style);
+    }
+
+    /// Fills the unit square of every cell that `color` gives a color, with
+    /// cell `(x, y)` covering `x..x + 1` and `y..y + 1`.
+    pub fn grid<T>(&mut self, grid: &Grid<T>, color: impl Fn(&T) -> Option<&str>) {
+        for (pos, cell) in grid.iter() {
+            if let Some(color) = color(cell) {
+                let min = Vec2::new(pos.x as f64, pos.y as f64);
+                let max = min + Vec2::new(1.0, 1.0);
+                self.shapes.push((Shape::Rect { min, max }, Style::fill(color)));
+            }
+        }
+
+        // Keep the whole grid in view even where nothing was drawn.
+        let corner = Vec2::new(grid.width() as f64, grid.height() as f64);
+        self.shapes.push((Shape::Rect { min: Vec2::new(0.0, 0.0), max: corner }, Style::stroke("none")));
+    }
+
+    /// Nodes at `nodes` joined by `edges`, given as pairs of node indices.
+    pub fn graph<T: Scalar>(&mut self, nodes: &[Vec2<T>], edges: &[(usize, usize)], node: Style, edge: Style) {
+        for &(from, to) in edges {
+            self.polyline([nodes[from], nodes[to]], edge.clone());
+        }
+        for &at in nodes {
+            self.point(at, node.clone());
+        }
+    }
+
+    /// Smallest and largest corner of everything drawn.
+    fn bounds(&self) -> (Vec2<f64>, Vec2<f64>) {
+        let points = self.shapes.iter().flat_map(|(shape, _)| shape.points()).collect::<Vec<_>>();
+        if points.is_empty() {
+            return (Vec2::new(0.0, 0.0), Vec2::new(1.0, 1.0));
+        }
+
+        let fold = |pick: fn(f64, f64) -> f64| {
+            points.iter().skip(1).fold(points[0], |acc, p| Vec2::new(pick(acc.x, p.x), pick(acc.y, p.y)))
+        };
+        let (min, mut max) = (fold(f64::min), fold(f64::max));
+
+        // A scene that is a single line or point still needs some area.
+        if max.x - min.x == 0.0 {
+            max.x = min.x + 1.0;
+        }
+        if max.y - min.y == 0.0 {
+            max.y = min.y + 1.0;
+        }
+        (min, max)
+    }
+
+    /// Renders the scene as a standalone SVG document.
+    pub fn to_svg(&self) -> String {
+        let (min, max) = self.bounds();
+        let size = max - min;
+        let scale = DRAWING_SIZE / size.x.max(size.y);
+        let (width, height) = (size.x * scale, size.y * scale);
+        // Pixel-sized values in puzzle coordinates, for point radii.
+        let pixel = 1.0 / scale;
+
+        let top = MARGIN + if self.title.is_some() { TITLE_HEIGHT } else { 0.0 };
+        let legend_width = if self.legend.is_empty() { 0.0 } else { LEGEND_WIDTH };
+        let total_width = width + 2.0 * MARGIN + legend_width;
+        let total_height = (height + top + MARGIN).max(top + self.legend.len() as f64 * 24.0 + MARGIN);
+
+        let mut svg = String::new();
+        let _ = writeln!(
+            svg,
+            r#"<svg xmlns="http://www.w3.org/2000/svg" viewBox="0 0 {:.0} {:.0}" width="{:.0}" height="{:.0}">"#,
+            total_width, total_height, total_width, total_height
+        );
+        let _ = writeln!(svg, r#"<rect width="100%" height="100%" fill="white"/>"#);
+
+        if let Some(title) = &self.title {
+            let _ = writeln!(
+                svg,
+                r#"<text x="{}" y="{}" font-family="sans-serif" font-size="20">{}</text>"#,
+                MARGIN,
+                MARGIN + 16.0,
+                escape(title)
+            );
+        }
+
+        let _ = writeln!(
+            svg,
+            r#"<svg x="{}" y="{}" width="{:.2}" height="{:.2}" viewBox="{} {} {} {}">"#,
+            MARGIN, top, width, height, min.x, min.y, size.x, size.y
+        );
+        for (shape, style) in &self.shapes {
+            svg += &shape_svg(shape, style, pixel);
+        }
+        svg += "</svg>\n";
+
+        for (i, style) in self.legend.iter().enumerate() {
+            let x = width + 2.0 * MARGIN;
+            let y = top + i as f64 * 24.0;
+            let swatch = style.fill.as_deref().or(style.stroke.as_deref()).unwrap_or("black");
+            let _ = writeln!(
+                svg,
+                r#"<rect x="{}" y="{}" width="16" height="16" fill="{}"/><text x="{}" y="{}" font-family="sans-serif" font-size="14">{}</text>"#,
+                x,
+                y,
+                escape(swatch),
+                x + 24.0,
+                y + 13.0,
+                escape(style.label.as_deref().unwrap_or_default())
+            );
+        }
+
+        svg += "</svg>\n";
+        svg
+    }
+
+    pub fn save(&self, path: &Path) -> std::io::Result<()> {
+        std::fs::write(path, self.to_svg())
+    }
+}
+
+fn paint(style: &Style) -> String {
+    format!(
+        r#"fill="{}" stroke="{}" stroke-width="{}" vector-effect="non-scaling-stroke""#,
+        escape(style.fill.as_deref().unwrap_or("none")),
+        escape(style.stroke.as_deref().unwrap_or("none")),
+        style.width
+    )
+}
+
+fn shape_svg(shape: &Shape, style: &Style, pixel: f64) -> String {
+    match shape {
+        Shape::Polyline { points, closed } => {
+            let points = points.iter().map(|p| format!("{},{}", p.x, p.y)).collect::<Vec<_>>().join(" ");
+            let element = if *closed { "polygon" } else { "polyline" };
+            format!("<{} points=\"{}\" {}/>\n", element, points, paint(style))
+        }
+        Shape::Rect { min, max } => format!(
+            "<rect x=\"{}\" y=\"{}\" width=\"{}\" height=\"{}\" {}/>\n",
+            min.x,
+            min.y,
+            max.x - min.x,
+            max.y - min.y,
+            paint(style)
+        ),
+        Shape::Point(at) => {
+            let color = style.fill.as_deref().or(style.stroke.as_deref()).unwrap_or("black");
+            format!(
+                "<circle cx=\"{}\" cy=\"{}\" r=\"{}\" fill=\"{}\"/>\n",
+                at.x,
+                at.y,
+                style.width * pixel,
+                escape(color)
+            )
+        }
+    }
+}
+
+/// Positions for `count` graph nodes spread evenly around a circle, for
+/// graphs whose nodes have no location of their own.
+pub fn circle_layout(count: usize) -> Vec<Vec2<f64>> {
+    let radius = (count as f64).max(1.0) * 10.0;
+    (0..count)
+        .map(|i| {
+            let angle = i as f64 / count as f64 * std::f64::consts::TAU;
+            Vec2::new(radius * angle.cos(), radius * angle.sin())
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn view_box_fits_the_shapes() {
+        let mut scene = Scene::new();
+        scene.polygon([Vec2::new(10, 20), Vec2::new(110, 20), Vec2::new(110, 70)], Style::stroke("blue"));
+        scene.point(Vec2::new(60, 45), Style::fill("red").width(4.0));
+
+        let svg = scene.to_svg();
+        assert!(svg.contains(r#"viewBox="10 20 100 50""#), "{}", svg);
+        assert!(svg.contains(r#"<polygon points="10,20 110,20 110,70""#), "{}", svg);
+        // 100 units wide fills 1000 pixels, so a 4 pixel radius is 0.4 units.
+        assert!(svg.contains(r#"r="0.4""#), "{}", svg);
+    }
+
+    #[test]
+    fn labelled_styles_appear_once_in_the_legend() {
+        let mut scene = Scene::new();
+        scene.line(&Line2 { start: Vec2::new(0, 0), end: Vec2::new(5, 5) }, Style::stroke("blue").label("edge"));
+        scene.line(&Line2 { start: Vec2::new(5, 0), end: Vec2::new(0, 5) }, Style::stroke("blue").label("edge"));
+        scene.legend(Style::fill("green").label("a <b> & c"));
+
+        let svg = scene.to_svg();
+        assert_eq!(svg.matches(">edge</text>").count(), 1);
+        assert!(svg.contains("a &lt;b&gt; &amp; c"));
+    }
+
+    #[test]
+    fn grids_draw_one_square_per_colored_cell() {
+        let grid = Grid::parse("#.\n.#", "`#` or `.`", |ch| Some(ch == '#')).unwrap();
+        let mut scene = Scene::new();
+        scene.grid(&grid, |&wall| wall.then_some("black"));
+
+        let svg = scene.to_svg();
+        assert_eq!(svg.matches(r#"fill="black""#).count(), 2);
+        assert!(svg.contains(r#"viewBox="0 0 2 2""#));
+    }
+}
@@ -0,0 +1,274 @@
+use std::any::Any;
+use std::fmt::{self, Display};
+use std::path::Path;
+use std::process::ExitCode;
+
+use clap::Parser;
+
+use crate::animate::Frame;
+use crate::command::{Command, Table, Usage};
+use crate::input::{DayArgs, Input};
+use crate::parse::ParseError;
+use crate::random::Rng;
+use crate::render::Scene;
+use crate::trace;
+
+/// Why a part has no answer for an input that parsed, such as a machine
+/// that no presses can solve or an answer too large for its type.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SolveError {
+    pub message: String,
+}
+
+impl SolveError {
+    pub fn new(message: impl Into<String>) -> Self {
+        Self { message: message.into() }
+    }
+}
+
+impl fmt::Display for SolveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.message)
+    }
+}
+
+impl std::error::Error for SolveError {}
+
+/// A single day's puzzle: how to parse the input and how to answer each part.
+///
+/// Any text either fails to parse or gets an answer or a [`SolveError`] for
+/// each part; parsing and solving never panic or run forever. The fuzz
+/// targets in `fuzz/` hold every day to this.
+pub trait Solution {
+    const DAY: u8;
+    /// How many parts have a working implementation. The runner skips the rest.
+    const PARTS: u8 = 2;
+    /// Identifies what the solver answers, for the runner's answer cache,
+    /// along with a hash of the day's source that the runner takes as it is
+    /// built. Editing the source is enough to drop cached answers; bump this
+    /// only when answers could change without it, like a dependency upgrade.
+    const VERSION: u32 = 1;
+
+    type Input: 'static;
+
+    fn parse(input: &str) -> Result<Self::Input, ParseError>;
+    fn part1(input: &Self::Input) -> Result<impl Display, SolveError>;
+    fn part2(input: &Self::Input) -> Result<impl Display, SolveError>;
+
+    /// A picture of the puzzle, for days that have one.
+    fn render(_input: &Self::Input) -> Option<Scene> {
+        None
+    }
+
+    /// The steps of the puzzle's simulation, for days that have one.
+    fn animate(_input: &Self::Input) -> Option<Vec<Frame>> {
+        None
+    }
+
+    /// A random input of roughly `size` lines or items, in the format
+    /// [`Solution::parse`] accepts, for days that can make one.
+    fn generate(_rng: &mut Rng, _size: usize) -> Option<String> {
+        None
+    }
+
+    /// Commands for poking at the parsed input from `aoc repl`, for days
+    /// that have some.
+    fn commands() -> Vec<Command<Self::Input>> {
+        Vec::new()
+    }
+}
+
+/// Object-safe view of a [`Solution`], so the runner can hold every day in
+/// one table.
+pub trait Puzzle: Sync {
+    fn day(&self) -> u8;
+    fn parts(&self) -> u8;
+    fn version(&self) -> u32;
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, ParseError>;
+    /// Solves `part` for input previously returned by [`Puzzle::parse_input`].
+    fn solve_part(&self, parsed: &dyn Any, part: u8) -> Result<String, SolveError>;
+    /// Draws input previously returned by [`Puzzle::parse_input`].
+    fn render(&self, parsed: &dyn Any) -> Option<Scene>;
+    /// Animates input previously returned by [`Puzzle::parse_input`].
+    fn animate(&self, parsed: &dyn Any) -> Option<Vec<Frame>>;
+    /// A random input made from `seed`; the same seed and size always give
+    /// the same input.
+    fn generate(&self, seed: u64, size: usize) -> Option<String>;
+    /// The commands [`Puzzle::run_command`] knows, in the order to list them.
+    fn commands(&self) -> Vec<Usage>;
+    /// Runs command `name` on input previously returned by
+    /// [`Puzzle::parse_input`], or returns `None` if the day has no such
+    /// command.
+    fn run_command(&self, parsed: &dyn Any, name: &str, args: &[&str]) -> Option<Result<Table, String>>;
+}
+
+impl<S: Solution + Sync> Puzzle for S {
+    fn day(&self) -> u8 {
+        S::DAY
+    }
+
+    fn parts(&self) -> u8 {
+        S::PARTS
+    }
+
+    fn version(&self) -> u32 {
+        S::VERSION
+    }
+
+    fn parse_input(&self, input: &str) -> Result<Box<dyn Any>, ParseError> {
+        let _span = trace::span("parse");
+        Ok(Box::new(S::parse(input)?))
+    }
+
+    fn solve_part(&self, parsed: &dyn Any, part: u8) -> Result<String, SolveError> {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .expect("parsed input belongs to a different day");
+
+        match part {
+            1 => {
+                let _span = trace::span("part 1");
+                S::part1(input).map(|answer| answer.to_string())
+            }
+            2 => {
+                let _span = trace::span("part 2");
+                S::part2(input).map(|answer| answer.to_string())
+            }
+            _ => panic!("day {} has no part {}", S::DAY, part),
+        }
+    }
+
+    fn render(&self, parsed: &dyn Any) -> Option<Scene> {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .expect("parsed input belongs to a different day");
+
+        S::render(input)
+    }
+
+    fn animate(&self, parsed: &dyn Any) -> Option<Vec<Frame>> {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .expect("parsed input belongs to a different day");
+
+        S::animate(input)
+    }
+
+    fn generate(&self, seed: u64, size: usize) -> Option<String> {
+        S::generate(&mut Rng::new(seed), size)
+    }
+
+    fn commands(&self) -> Vec<Usage> {
+        S::commands().iter().map(|command| command.usage).collect()
+    }
+
+    fn run_command(&self, parsed: &dyn Any, name: &str, args: &[&str]) -> Option<Result<Table, String>> {
+        let input = parsed
+            .downcast_ref::<S::Input>()
+            .expect("parsed input belongs to a different day");
+
+        let command = S::commands().into_iter().find(|command| command.usage.name == name)?;
+        Some((command.run)(input, args))
+    }
+}
+
+/// Parses `input` once and answers each requested part, as the lines
+/// [`run_parts`] prints. A part without an answer gives an error line.
+pub fn solve_parts(puzzle: &dyn Puzzle, input: &str, parts: &[u8]) -> Result<Vec<Result<String, String>>, ParseError> {
+    let parsed = puzzle.parse_input(input)?;
+
+    Ok(parts
+        .iter()
+        .map(|&part| {
+            if part > puzzle.parts() {
+                return Ok(format!("Day {:02} part {}: not implemented", puzzle.day(), part));
+            }
+
+            match puzzle.solve_part(parsed.as_ref(), part) {
+                Ok(answer) => Ok(format!("Day {:02} part {}: {}", puzzle.day(), part, answer)),
+                Err(err) => Err(format!("Day {:02} part {}: error: {}", puzzle.day(), part, err)),
+            }
+        })
+        .collect())
+}
+
+/// Parses `input` once and prints the answer to each requested part, and
+/// the error for each part without one. Returns whether every part was
+/// answered.
+pub fn run_parts(puzzle: &dyn Puzzle, input: &str, parts: &[u8]) -> Result<bool, ParseError> {
+    let mut answered = true;
+
+    for line in solve_parts(puzzle, input, parts)? {
+        match line {
+            Ok(line) => println!("{}", line),
+            Err(line) => {
+                eprintln!("{}", line);
+                answered = false;
+            }
+        }
+    }
+
+    Ok(answered)
+}
+
+/// Runs both parts on `input`, printing a diagnostic if it does not parse.
+pub fn run<S: Solution + Sync>(puzzle: &S, input: &Input) -> ExitCode {
+    let outcome = {
+        let _span = trace::span(format!("day {:02}", S::DAY));
+        run_parts(puzzle, &input.text, &[1, 2])
+    };
+    trace::report();
+
+    match outcome {
+        Ok(true) => ExitCode::SUCCESS,
+        Ok(false) => ExitCode::FAILURE,
+        Err(err) => {
+            eprint!("{}", err.render(&input.origin, &input.text));
+            ExitCode::FAILURE
+        }
+    }
+}
+
+/// Parses `input` and saves the day's picture of it to `path`.
+pub fn render_to(puzzle: &dyn Puzzle, input: &Input, path: &Path) -> Result<(), String> {
+    let parsed = puzzle
+        .parse_input(&input.text)
+        .map_err(|err| format!("{}:{}:{}: {}", input.origin, err.line, err.column, err))?;
+    let scene = puzzle
+        .render(parsed.as_ref())
+        .ok_or_else(|| format!("day {} has nothing to render", puzzle.day()))?;
+
+    scene
+        .save(path)
+        .map_err(|err| format!("cannot write {}: {}", path.display(), err))
+}
+
+/// Entry point for a day's own binary: loads the input selected on the
+/// command line, relative to `day_dir`, runs both parts and saves a picture
+/// if `--render` was given.
+pub fn main<S: Solution + Sync>(puzzle: S, day_dir: &str) -> ExitCode {
+    let args = DayArgs::parse();
+
+    let input = match args.input.source().load(Path::new(day_dir)) {
+        Ok(input) => input,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+    };
+
+    let code = run(&puzzle, &input);
+    if code != ExitCode::SUCCESS {
+        return code;
+    }
+
+    if let Some(path) = &args.render {
+        if let Err(err) = render_to(&puzzle, &input, path) {
+            eprintln!("error: {}", err);
+            return ExitCode::FAILURE;
+        }
+        println!("Picture saved to {}", path.display());
+    }
+
+    ExitCode::SUCCESS
+}
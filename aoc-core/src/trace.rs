@@ -0,0 +1,315 @@
+//! Timed spans and named counters that show where a solver spends its work,
+//! collected when the `trace` feature is on and printed as a table with
+//! [`take`]. With the feature off every call here is an empty inline
+//! function, so solvers can stay instrumented at no cost.
+//!
+//! Spans nest per thread: `day 10 / part 2` is a `part 2` span opened inside
+//! a `day 10` one. Counters and gauges belong to the innermost open span.
+//! Work handed to [`crate::parallel::map`] stays inside the span that
+//! handed it out.
+
+use std::borrow::Cow;
+use std::collections::BTreeMap;
+use std::fmt;
+use std::time::Duration;
+
+/// Whether the `trace` feature is on, and spans and counters are kept.
+pub const ENABLED: bool = cfg!(feature = "trace");
+
+/// A counter's value, merged across calls and threads.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Value {
+    /// Added up, like states expanded.
+    Count(u64),
+    /// The largest value seen, like a queue's peak length.
+    Gauge(u64),
+}
+
+impl Value {
+    #[cfg_attr(not(feature = "trace"), allow(dead_code))]
+    fn merge(self, other: Value) -> Value {
+        match (self, other) {
+            (Value::Count(a), Value::Count(b)) => Value::Count(a + b),
+            (Value::Gauge(a), Value::Gauge(b)) => Value::Gauge(a.max(b)),
+            // A name used both ways keeps whichever came first.
+            (value, _) => value,
+        }
+    }
+
+    pub fn get(self) -> u64 {
+        match self {
+            Value::Count(n) | Value::Gauge(n) => n,
+        }
+    }
+}
+
+/// What was recorded under one span path.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SpanStats {
+    pub calls: u64,
+    pub total: Duration,
+    pub counters: BTreeMap<&'static str, Value>,
+}
+
+/// Everything recorded since the last [`take`], by span path.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct Summary {
+    pub spans: BTreeMap<String, SpanStats>,
+}
+
+impl Summary {
+    pub fn is_empty(&self) -> bool {
+        self.spans.is_empty()
+    }
+
+    #[cfg_attr(not(feature = "trace"), allow(dead_code))]
+    fn merge(&mut self, path: &str, elapsed: Option<Duration>, counters: BTreeMap<&'static str, Value>) {
+        let stats = self.spans.entry(path.to_string()).or_default();
+        if let Some(elapsed) = elapsed {
+            stats.calls += 1;
+            stats.total += elapsed;
+        }
+        for (name, value) in counters {
+            stats
+                .counters
+                .entry(name)
+                .and_modify(|existing| *existing = existing.merge(value))
+                .or_insert(value);
+        }
+    }
+}
+
+fn format_duration(duration: Duration) -> String {
+    let nanos = duration.as_nanos();
+    if nanos >= 1_000_000_000 {
+        format!("{:.2} s", duration.as_secs_f64())
+    } else if nanos >= 1_000_000 {
+        format!("{:.2} ms", nanos as f64 / 1e6)
+    } else {
+        format!("{:.2} µs", nanos as f64 / 1e3)
+    }
+}
+
+/// A table with one row per span, its calls, total time and counters.
+impl fmt::Display for Summary {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let width = self.spans.keys().map(|path| path.chars().count()).max().unwrap_or(0).max(4);
+
+        writeln!(f, "{:<width$}  {:>6}  {:>10}  counters", "span", "calls", "total")?;
+        for (path, stats) in &self.spans {
+            let total = if stats.calls > 0 { format_duration(stats.total) } else { "-".to_string() };
+            let counters = stats
+                .counters
+                .iter()
+                .map(|(name, value)| match value {
+                    Value::Count(n) => format!("{} {}", name, n),
+                    Value::Gauge(n) => format!("{} max {}", name, n),
+                })
+                .collect::<Vec<_>>()
+                .join(", ");
+
+            let row = format!("{:<width$}  {:>6}  {:>10}  {}", path, stats.calls, total, counters);
+            writeln!(f, "{}", row.trim_end())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "trace")]
+mod state {
+    use std::cell::RefCell;
+    use std::collections::BTreeMap;
+    use std::sync::Mutex;
+    use std::time::Instant;
+
+    use super::{Summary, Value};
+
+    /// An open span on this thread. Counters gather here and move to the
+    /// summary when it closes.
+    pub struct Frame {
+        pub path: String,
+        /// When the span opened; `None` for a thread taking over someone
+        /// else's span, which is not timed again.
+        pub start: Option<Instant>,
+        pub counters: BTreeMap<&'static str, Value>,
+    }
+
+    thread_local! {
+        pub static STACK: RefCell<Vec<Frame>> = const { RefCell::new(Vec::new()) };
+    }
+
+    pub static SUMMARY: Mutex<Summary> = Mutex::new(Summary { spans: BTreeMap::new() });
+
+    pub fn push(path: String, start: Option<Instant>) {
+        STACK.with(|stack| stack.borrow_mut().push(Frame { path, start, counters: BTreeMap::new() }));
+    }
+
+    pub fn pop() {
+        let Some(frame) = STACK.with(|stack| stack.borrow_mut().pop()) else { return };
+        let elapsed = frame.start.map(|start| start.elapsed());
+
+        SUMMARY
+            .lock()
+            .unwrap_or_else(|poisoned| poisoned.into_inner())
+            .merge(&frame.path, elapsed, frame.counters);
+    }
+
+    pub fn current() -> Option<String> {
+        STACK.with(|stack| stack.borrow().last().map(|frame| frame.path.clone()))
+    }
+
+    pub fn record(name: &'static str, value: Value) {
+        STACK.with(|stack| match stack.borrow_mut().last_mut() {
+            Some(frame) => {
+                frame.counters.entry(name).and_modify(|existing| *existing = existing.merge(value)).or_insert(value);
+            }
+            None => {
+                let counters = BTreeMap::from([(name, value)]);
+                SUMMARY.lock().unwrap_or_else(|poisoned| poisoned.into_inner()).merge("-", None, counters);
+            }
+        });
+    }
+}
+
+/// An open span; it closes, and its time is recorded, when dropped.
+#[must_use = "a span closes as soon as it is dropped"]
+pub struct Span {
+    #[cfg(feature = "trace")]
+    _open: (),
+}
+
+#[cfg(feature = "trace")]
+impl Drop for Span {
+    fn drop(&mut self) {
+        state::pop();
+    }
+}
+
+/// Opens a span named `name` inside the thread's current one.
+#[cfg(feature = "trace")]
+pub fn span(name: impl Into<Cow<'static, str>>) -> Span {
+    let name = name.into();
+    let path = match state::current() {
+        Some(parent) => format!("{} / {}", parent, name),
+        None => name.into_owned(),
+    };
+
+    state::push(path, Some(std::time::Instant::now()));
+    Span { _open: () }
+}
+
+/// Opens a span named `name` inside the thread's current one.
+#[cfg(not(feature = "trace"))]
+#[inline(always)]
+pub fn span(_name: impl Into<Cow<'static, str>>) -> Span {
+    Span {}
+}
+
+/// Adds `n` to the counter `name` of the current span.
+#[cfg(feature = "trace")]
+pub fn count(name: &'static str, n: u64) {
+    state::record(name, Value::Count(n));
+}
+
+/// Adds `n` to the counter `name` of the current span.
+#[cfg(not(feature = "trace"))]
+#[inline(always)]
+pub fn count(_name: &'static str, _n: u64) {}
+
+/// Raises the gauge `name` of the current span to `value`, if it is higher.
+#[cfg(feature = "trace")]
+pub fn gauge(name: &'static str, value: u64) {
+    state::record(name, Value::Gauge(value));
+}
+
+/// Raises the gauge `name` of the current span to `value`, if it is higher.
+#[cfg(not(feature = "trace"))]
+#[inline(always)]
+pub fn gauge(_name: &'static str, _value: u64) {}
+
+/// The span a thread is in, to carry over to other threads with [`within`].
+#[derive(Debug, Clone, Default)]
+pub struct Context {
+    #[cfg(feature = "trace")]
+    path: Option<String>,
+}
+
+/// The calling thread's current span.
+#[cfg(feature = "trace")]
+pub fn current() -> Context {
+    Context { path: state::current() }
+}
+
+/// The calling thread's current span.
+#[cfg(not(feature = "trace"))]
+#[inline(always)]
+pub fn current() -> Context {
+    Context {}
+}
+
+/// Runs `f` as part of the span in `context`, usually on another thread.
+#[cfg(feature = "trace")]
+pub fn within<R>(context: &Context, f: impl FnOnce() -> R) -> R {
+    let Some(path) = &context.path else { return f() };
+
+    state::push(path.clone(), None);
+    let _frame = Span { _open: () };
+    f()
+}
+
+/// Runs `f` as part of the span in `context`, usually on another thread.
+#[cfg(not(feature = "trace"))]
+#[inline(always)]
+pub fn within<R>(_context: &Context, f: impl FnOnce() -> R) -> R {
+    f()
+}
+
+/// Everything recorded so far by closed spans, leaving nothing behind.
+#[cfg(feature = "trace")]
+pub fn take() -> Summary {
+    std::mem::take(&mut *state::SUMMARY.lock().unwrap_or_else(|poisoned| poisoned.into_inner()))
+}
+
+/// Everything recorded so far by closed spans, leaving nothing behind.
+#[cfg(not(feature = "trace"))]
+pub fn take() -> Summary {
+    Summary::default()
+}
+
+/// Prints the table of everything recorded so far to stderr, if there is
+/// anything, and starts over.
+pub fn report() {
+    let summary = take();
+    if !summary.is_empty() {
+        eprint!("\n{}", summary);
+    }
+}
+
+#[cfg(all(test, feature = "trace"))]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn nests_spans_and_merges_counters() {
+        {
+            let _day = span("trace test");
+            for n in [3, 5] {
+                let _part = span("part 1");
+                count("states", n);
+                gauge("queue", n);
+            }
+            let context = current();
+            std::thread::spawn(move || within(&context, || count("states", 2))).join().unwrap();
+        }
+
+        let summary = take();
+        let day = &summary.spans["trace test"];
+        let part = &summary.spans["trace test / part 1"];
+
+        assert_eq!((day.calls, part.calls), (1, 2));
+        assert_eq!(day.counters["states"], Value::Count(2));
+        assert_eq!(part.counters["states"], Value::Count(8));
+        assert_eq!(part.counters["queue"], Value::Gauge(5));
+        assert!(summary.to_string().contains("queue max 5, states 8"));
+    }
+}
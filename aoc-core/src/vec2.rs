@@ -0,0 +1,174 @@
+use crate::scalar::Scalar;
+
+/// A 2D vector, used both as a position and as an offset.
+///
+/// Grids use screen coordinates: `x` grows to the right and `y` grows down.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Vec2<T> {
+    pub x: T,
+    pub y: T,
+}
+
+impl<T> Vec2<T> {
+    pub const fn new(x: T, y: T) -> Self {
+        Self { x, y }
+    }
+}
+
+impl<T: Default> Vec2<T> {
+    /// A vector along the x axis.
+    pub fn x(x: T) -> Self {
+        Self { x, y: T::default() }
+    }
+
+    /// A vector along the y axis.
+    pub fn y(y: T) -> Self {
+        Self { x: T::default(), y }
+    }
+}
+
+impl<T: Scalar> Vec2<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        let dx = self.x.abs_diff(other.x);
+        let dy = self.y.abs_diff(other.y);
+        if dx > dy {
+            dx
+        } else {
+            dy
+        }
+    }
+
+    pub fn distance_squared(&self, other: &Self) -> T {
+        let dx = self.x.abs_diff(other.x);
+        let dy = self.y.abs_diff(other.y);
+        dx * dx + dy * dy
+    }
+
+    /// Straight-line distance. The components are converted to `f64` before
+    /// squaring so large coordinates cannot overflow `T`.
+    pub fn euclidean(&self, other: &Self) -> f64 {
+        let dx = self.x.abs_diff(other.x).to_f64();
+        let dy = self.y.abs_diff(other.y).to_f64();
+        ((dx * dx) + (dy * dy)).sqrt()
+    }
+}
+
+impl Vec2<usize> {
+    /// Adds a signed offset, returning `None` if the result would leave the
+    /// non-negative quadrant.
+    pub fn checked_offset(self, offset: Vec2<isize>) -> Option<Self> {
+        Some(Self {
+            x: self.x.checked_add_signed(offset.x)?,
+            y: self.y.checked_add_signed(offset.y)?,
+        })
+    }
+}
+
+impl<T: std::ops::Add<Output = T>> std::ops::Add for Vec2<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+        }
+    }
+}
+
+impl<T: std::ops::AddAssign> std::ops::AddAssign for Vec2<T> {
+    fn add_assign(&mut self, other: Self) {
+        self.x += other.x;
+        self.y += other.y;
+    }
+}
+
+impl<T: std::ops::Sub<Output = T>> std::ops::Sub for Vec2<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+        }
+    }
+}
+
+impl<T: std::ops::SubAssign> std::ops::SubAssign for Vec2<T> {
+    fn sub_assign(&mut self, other: Self) {
+        self.x -= other.x;
+        self.y -= other.y;
+    }
+}
+
+impl<T: std::ops::Mul<Output = T> + Copy> std::ops::Mul<T> for Vec2<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        Self {
+            x: self.x * scalar,
+            y: self.y * scalar,
+        }
+    }
+}
+
+impl<T: std::ops::Neg<Output = T>> std::ops::Neg for Vec2<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+        }
+    }
+}
+
+impl<T> From<(T, T)> for Vec2<T> {
+    fn from((x, y): (T, T)) -> Self {
+        Self { x, y }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec2::new(3, -2);
+        let b = Vec2::new(1, 5);
+        assert_eq!(a + b, Vec2::new(4, 3));
+        assert_eq!(a - b, Vec2::new(2, -7));
+        assert_eq!(a * 3, Vec2::new(9, -6));
+        assert_eq!(-a, Vec2::new(-3, 2));
+        assert_eq!(Vec2::x(4) + Vec2::y(7), Vec2::new(4, 7));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec2::new(1i64, 1);
+        let b = Vec2::new(4i64, 5);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(a.chebyshev(&b), 4);
+        assert_eq!(a.distance_squared(&b), 25);
+        assert_eq!(a.euclidean(&b), 5.0);
+    }
+
+    #[test]
+    fn unsigned_distances_do_not_underflow() {
+        let a = Vec2::new(5usize, 0);
+        let b = Vec2::new(2usize, 4);
+        assert_eq!(a.manhattan(&b), 7);
+        assert_eq!(b.manhattan(&a), 7);
+    }
+
+    #[test]
+    fn checked_offset() {
+        let p = Vec2::new(0usize, 2);
+        assert_eq!(p.checked_offset(Vec2::new(1, -2)), Some(Vec2::new(1, 0)));
+        assert_eq!(p.checked_offset(Vec2::new(-1, 0)), None);
+    }
+}
@@ -0,0 +1,127 @@
+use crate::scalar::Scalar;
+
+/// A 3D vector, used both as a position and as an offset.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default, PartialOrd, Ord)]
+pub struct Vec3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T> Vec3<T> {
+    pub const fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Scalar> Vec3<T> {
+    pub fn manhattan(&self, other: &Self) -> T {
+        self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+    }
+
+    pub fn chebyshev(&self, other: &Self) -> T {
+        let mut max = self.x.abs_diff(other.x);
+        for d in [self.y.abs_diff(other.y), self.z.abs_diff(other.z)] {
+            if d > max {
+                max = d;
+            }
+        }
+        max
+    }
+
+    pub fn distance_squared(&self, other: &Self) -> T {
+        let dx = self.x.abs_diff(other.x);
+        let dy = self.y.abs_diff(other.y);
+        let dz = self.z.abs_diff(other.z);
+        dx * dx + dy * dy + dz * dz
+    }
+
+    /// Straight-line distance. The components are converted to `f64` before
+    /// squaring so large coordinates cannot overflow `T`.
+    pub fn euclidean(&self, other: &Self) -> f64 {
+        let dx = self.x.abs_diff(other.x).to_f64();
+        let dy = self.y.abs_diff(other.y).to_f64();
+        let dz = self.z.abs_diff(other.z).to_f64();
+        ((dx * dx) + (dy * dy) + (dz * dz)).sqrt()
+    }
+}
+
+impl<T: std::ops::Add<Output = T>> std::ops::Add for Vec3<T> {
+    type Output = Self;
+
+    fn add(self, other: Self) -> Self {
+        Self {
+            x: self.x + other.x,
+            y: self.y + other.y,
+            z: self.z + other.z,
+        }
+    }
+}
+
+impl<T: std::ops::Sub<Output = T>> std::ops::Sub for Vec3<T> {
+    type Output = Self;
+
+    fn sub(self, other: Self) -> Self {
+        Self {
+            x: self.x - other.x,
+            y: self.y - other.y,
+            z: self.z - other.z,
+        }
+    }
+}
+
+impl<T: std::ops::Mul<Output = T> + Copy> std::ops::Mul<T> for Vec3<T> {
+    type Output = Self;
+
+    fn mul(self, scalar: T) -> Self {
+        Self {
+            x: self.x * scalar,
+            y: self.y * scalar,
+            z: self.z * scalar,
+        }
+    }
+}
+
+impl<T: std::ops::Neg<Output = T>> std::ops::Neg for Vec3<T> {
+    type Output = Self;
+
+    fn neg(self) -> Self {
+        Self {
+            x: -self.x,
+            y: -self.y,
+            z: -self.z,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn arithmetic() {
+        let a = Vec3::new(1, 2, 3);
+        let b = Vec3::new(-4, 0, 6);
+        assert_eq!(a + b, Vec3::new(-3, 2, 9));
+        assert_eq!(a - b, Vec3::new(5, 2, -3));
+        assert_eq!(a * 2, Vec3::new(2, 4, 6));
+        assert_eq!(-a, Vec3::new(-1, -2, -3));
+    }
+
+    #[test]
+    fn distances() {
+        let a = Vec3::new(0i32, 0, 0);
+        let b = Vec3::new(2i32, -3, 6);
+        assert_eq!(a.manhattan(&b), 11);
+        assert_eq!(a.chebyshev(&b), 6);
+        assert_eq!(a.distance_squared(&b), 49);
+        assert_eq!(a.euclidean(&b), 7.0);
+    }
+
+    #[test]
+    fn euclidean_does_not_overflow() {
+        let a = Vec3::new(0i32, 0, 0);
+        let b = Vec3::new(100_000i32, 100_000, 100_000);
+        assert!((a.euclidean(&b) - 173_205.080_756_887_7).abs() < 1e-6);
+    }
+}
@@ -0,0 +1,42 @@
+//! Hashes the source of every day crate in the workspace, along with the
+//! `aoc-core` code they share, into `$OUT_DIR/fingerprints.rs` for the
+//! answer cache.
+
+#[path = "src/fingerprint.rs"]
+mod fingerprint;
+
+use std::path::Path;
+
+fn main() {
+    let manifest_dir = std::env::var("CARGO_MANIFEST_DIR").unwrap();
+    let root = Path::new(&manifest_dir).parent().unwrap();
+    let core = root.join("aoc-core");
+    let (core_src, core_manifest) = (core.join("src"), core.join("Cargo.toml"));
+
+    // New days are added to the runner's manifest as they are scaffolded
+    println!("cargo:rerun-if-changed=Cargo.toml");
+    println!("cargo:rerun-if-changed={}", core_src.display());
+    println!("cargo:rerun-if-changed={}", core_manifest.display());
+
+    let mut days = vec![];
+    for entry in std::fs::read_dir(root).unwrap() {
+        let year_dir = entry.unwrap().path();
+        let Some(year) = year_dir.file_name().and_then(|name| name.to_str()?.parse::<u16>().ok()) else { continue };
+
+        for entry in std::fs::read_dir(&year_dir).unwrap() {
+            let day_dir = entry.unwrap().path();
+            let day = day_dir.file_name().and_then(|name| name.to_str()?.strip_prefix("day")?.parse::<u8>().ok());
+            let (src, manifest) = (day_dir.join("src"), day_dir.join("Cargo.toml"));
+            let Some(day) = day.filter(|_| src.is_dir() && manifest.is_file()) else { continue };
+
+            println!("cargo:rerun-if-changed={}", src.display());
+            println!("cargo:rerun-if-changed={}", manifest.display());
+            let hash = fingerprint::hash(&[&src, &manifest, &core_src, &core_manifest]).unwrap();
+            days.push(format!("    ({}, {}, \"{}\"),\n", year, day, hash));
+        }
+    }
+    days.sort();
+
+    let out = Path::new(&std::env::var("OUT_DIR").unwrap()).join("fingerprints.rs");
+    std::fs::write(out, format!("static FINGERPRINTS: &[(u16, u8, &str)] = &[\n{}];\n", days.concat())).unwrap();
+}
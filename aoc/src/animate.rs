@@ -0,0 +1,296 @@
+use std::io::{self, IsTerminal, Write};
+use std::time::{Duration, Instant};
+
+use aoc_core::{Color, Frame, Glyph, Grid, Vec2};
+use crossterm::event::{self, Event, KeyCode, KeyEvent, KeyModifiers};
+use crossterm::terminal;
+
+/// How frames larger than the screen are shrunk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, clap::ValueEnum)]
+pub enum Fit {
+    /// Show only the top left corner.
+    Crop,
+    /// Merge blocks of cells into one, keeping whatever is not blank.
+    Downsample,
+}
+
+/// Shrinks `glyphs` to at most `max` cells wide and high.
+pub fn fit(glyphs: &Grid<Glyph>, max: Vec2<usize>, mode: Fit) -> Grid<Glyph> {
+    let (max_x, max_y) = (max.x.max(1), max.y.max(1));
+    if glyphs.width() <= max_x && glyphs.height() <= max_y {
+        return glyphs.clone();
+    }
+
+    match mode {
+        Fit::Crop => {
+            let (width, height) = (glyphs.width().min(max_x), glyphs.height().min(max_y));
+            Grid::new(width, height, Glyph::BLANK).map(|pos, _| glyphs[pos])
+        }
+        Fit::Downsample => {
+            let block = glyphs.width().div_ceil(max_x).max(glyphs.height().div_ceil(max_y));
+            let (width, height) = (glyphs.width().div_ceil(block), glyphs.height().div_ceil(block));
+
+            Grid::new(width, height, Glyph::BLANK).map(|pos, _| {
+                let cells = (0..block)
+                    .flat_map(|dy| (0..block).map(move |dx| Vec2::new(pos.x * block + dx, pos.y * block + dy)))
+                    .filter_map(|pos| glyphs.get(pos).copied())
+                    .collect::<Vec<_>>();
+
+                cells.iter().copied().find(|glyph| !glyph.is_blank()).unwrap_or(cells[0])
+            })
+        }
+    }
+}
+
+fn ansi(color: Color) -> &'static str {
+    match color {
+        Color::Default => "\x1b[0m",
+        Color::Red => "\x1b[31m",
+        Color::Green => "\x1b[32m",
+        Color::Yellow => "\x1b[33m",
+        Color::Blue => "\x1b[34m",
+        Color::Magenta => "\x1b[35m",
+        Color::Cyan => "\x1b[36m",
+        Color::Gray => "\x1b[90m",
+    }
+}
+
+/// Draws `frame` from the top left of the screen, with its caption and
+/// `status` underneath. Lines end in `\r\n` so the output also works with
+/// the terminal in raw mode.
+pub fn draw(frame: &Frame, max: Vec2<usize>, mode: Fit, status: &str) -> String {
+    let glyphs = fit(&frame.glyphs, max, mode);
+    let mut out = String::from("\x1b[H");
+
+    for row in glyphs.rows() {
+        let mut color = Color::Default;
+        for glyph in row {
+            if glyph.color != color {
+                out += ansi(glyph.color);
+                color = glyph.color;
+            }
+            out.push(glyph.ch);
+        }
+        out += "\x1b[0m\x1b[K\r\n";
+    }
+
+    out += &format!("\x1b[K{}\r\n\x1b[K{}\x1b[J", frame.caption, status);
+    out
+}
+
+/// What the viewer asked for.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Control {
+    TogglePause,
+    Forward,
+    Back,
+    Faster,
+    Slower,
+    Quit,
+}
+
+/// Where controls come from: the keyboard, or a script in tests.
+pub trait Controls {
+    /// Waits up to `timeout` for a control; `None` for no timeout.
+    fn next(&mut self, timeout: Option<Duration>) -> Option<Control>;
+}
+
+/// Reads single key presses, with the terminal in raw mode for as long as
+/// this lives.
+pub struct Keyboard;
+
+impl Keyboard {
+    pub fn new() -> io::Result<Self> {
+        terminal::enable_raw_mode()?;
+        Ok(Keyboard)
+    }
+}
+
+impl Drop for Keyboard {
+    fn drop(&mut self) {
+        let _ = terminal::disable_raw_mode();
+    }
+}
+
+fn control(key: KeyEvent) -> Option<Control> {
+    match key.code {
+        KeyCode::Char(' ') => Some(Control::TogglePause),
+        KeyCode::Right | KeyCode::Char('n') => Some(Control::Forward),
+        KeyCode::Left | KeyCode::Char('p') => Some(Control::Back),
+        KeyCode::Char('+') | KeyCode::Char('=') => Some(Control::Faster),
+        KeyCode::Char('-') => Some(Control::Slower),
+        KeyCode::Char('c') if key.modifiers.contains(KeyModifiers::CONTROL) => Some(Control::Quit),
+        KeyCode::Char('q') | KeyCode::Esc => Some(Control::Quit),
+        _ => None,
+    }
+}
+
+impl Controls for Keyboard {
+    fn next(&mut self, timeout: Option<Duration>) -> Option<Control> {
+        let deadline = timeout.map(|timeout| Instant::now() + timeout);
+
+        loop {
+            let wait = match deadline {
+                Some(deadline) => deadline.saturating_duration_since(Instant::now()),
+                None => Duration::from_secs(3600),
+            };
+            if !event::poll(wait).ok()? {
+                if deadline.is_some() {
+                    return None;
+                }
+                continue;
+            }
+
+            if let Ok(Event::Key(key)) = event::read() {
+                if let Some(control) = control(key) {
+                    return Some(control);
+                }
+            }
+        }
+    }
+}
+
+/// Plays frames without listening for keys, for output that is not a
+/// terminal.
+pub struct NoControls;
+
+impl Controls for NoControls {
+    fn next(&mut self, timeout: Option<Duration>) -> Option<Control> {
+        std::thread::sleep(timeout?);
+        None
+    }
+}
+
+/// Playback settings.
+#[derive(Debug, Clone, Copy)]
+pub struct Player {
+    pub fps: f64,
+    /// Largest frame drawn, in cells.
+    pub max: Vec2<usize>,
+    pub fit: Fit,
+    pub paused: bool,
+}
+
+impl Player {
+    /// Plays `frames` to `out`, following `controls`, and returns the index
+    /// of the frame it stopped on.
+    pub fn play(mut self, frames: &[Frame], out: &mut impl Write, controls: &mut impl Controls) -> io::Result<usize> {
+        if frames.is_empty() {
+            return Ok(0);
+        }
+
+        write!(out, "\x1b[2J")?;
+        let mut index = 0;
+
+        loop {
+            let status = format!(
+                "frame {}/{}  {:.0} fps{}  [space] pause  [←/→] step  [+/-] speed  [q] quit",
+                index + 1,
+                frames.len(),
+                self.fps,
+                if self.paused { "  paused" } else { "" },
+            );
+            write!(out, "{}", draw(&frames[index], self.max, self.fit, &status))?;
+            out.flush()?;
+
+            let last = index + 1 == frames.len();
+            let timeout = if self.paused { None } else { Some(Duration::from_secs_f64(1.0 / self.fps)) };
+
+            match controls.next(timeout) {
+                Some(Control::Quit) => break,
+                Some(Control::TogglePause) => self.paused = !self.paused,
+                Some(Control::Forward) => {
+                    self.paused = true;
+                    index = (index + 1).min(frames.len() - 1);
+                }
+                Some(Control::Back) => {
+                    self.paused = true;
+                    index = index.saturating_sub(1);
+                }
+                Some(Control::Faster) => self.fps = (self.fps * 2.0).min(240.0),
+                Some(Control::Slower) => self.fps = (self.fps / 2.0).max(0.25),
+                // Nothing more will come while paused, so there is no way on.
+                None if self.paused => break,
+                None if last => break,
+                None => index = (index + 1).min(frames.len() - 1),
+            }
+        }
+
+        write!(out, "\r\n")?;
+        Ok(index)
+    }
+}
+
+/// Whether both ends of the terminal are interactive, so keys can be read.
+pub fn interactive() -> bool {
+    io::stdin().is_terminal() && io::stdout().is_terminal()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn glyphs(text: &str) -> Grid<Glyph> {
+        Grid::parse(text, "any character", |ch| Some(Glyph::new(ch, Color::Default))).unwrap()
+    }
+
+    fn text(grid: &Grid<Glyph>) -> String {
+        grid.rows().map(|row| row.iter().map(|glyph| glyph.ch).collect::<String>()).collect::<Vec<_>>().join("\n")
+    }
+
+    struct Script(Vec<Control>);
+
+    impl Controls for Script {
+        fn next(&mut self, _timeout: Option<Duration>) -> Option<Control> {
+            (!self.0.is_empty()).then(|| self.0.remove(0))
+        }
+    }
+
+    fn frames(count: usize) -> Vec<Frame> {
+        (0..count).map(|i| Frame { caption: format!("caption {}", i), glyphs: glyphs("ab") }).collect()
+    }
+
+    fn player(paused: bool) -> Player {
+        Player { fps: 1000.0, max: Vec2::new(80, 24), fit: Fit::Crop, paused }
+    }
+
+    #[test]
+    fn crops_to_the_top_left() {
+        let grid = glyphs("abcd\nefgh\nijkl");
+        assert_eq!(text(&fit(&grid, Vec2::new(2, 2), Fit::Crop)), "ab\nef");
+    }
+
+    #[test]
+    fn downsampling_keeps_features() {
+        let grid = glyphs("....\n.#..\n....\n...#");
+        assert_eq!(text(&fit(&grid, Vec2::new(2, 2), Fit::Downsample)), "#.\n.#");
+    }
+
+    #[test]
+    fn draws_colors_and_caption() {
+        let mut grid = glyphs("ab");
+        grid[Vec2::new(1, 0)].color = Color::Red;
+        let frame = Frame { caption: "round 1".to_string(), glyphs: grid };
+
+        let out = draw(&frame, Vec2::new(80, 24), Fit::Crop, "status");
+        assert!(out.starts_with("\x1b[Ha\x1b[31mb\x1b[0m"), "{:?}", out);
+        assert!(out.contains("round 1\r\n"));
+    }
+
+    #[test]
+    fn plays_through_to_the_end() {
+        let mut out = vec![];
+        let stopped = player(false).play(&frames(3), &mut out, &mut Script(vec![])).unwrap();
+
+        assert_eq!(stopped, 2);
+        assert_eq!(String::from_utf8(out).unwrap().matches("caption ").count(), 3);
+    }
+
+    #[test]
+    fn steps_while_paused() {
+        let controls = vec![Control::Forward, Control::Forward, Control::Back, Control::Quit];
+        let stopped = player(true).play(&frames(5), &mut vec![], &mut Script(controls)).unwrap();
+
+        assert_eq!(stopped, 1);
+    }
+}
@@ -0,0 +1,238 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::hint::black_box;
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc_core::{Input, Puzzle};
+use serde::{Deserialize, Serialize};
+
+/// Timing summary of repeated runs of one phase.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Stats {
+    pub runs: usize,
+    pub median_ns: u64,
+    pub min_ns: u64,
+    pub stddev_ns: u64,
+}
+
+impl Stats {
+    pub fn from_samples(samples: &[Duration]) -> Self {
+        let mut nanos = samples.iter().map(|d| d.as_nanos() as u64).collect::<Vec<_>>();
+        nanos.sort_unstable();
+
+        let n = nanos.len();
+        let median_ns = match n {
+            0 => 0,
+            _ if n % 2 == 1 => nanos[n / 2],
+            _ => (nanos[n / 2 - 1] + nanos[n / 2]) / 2,
+        };
+
+        let mean = nanos.iter().sum::<u64>() as f64 / n.max(1) as f64;
+        let variance = nanos.iter().map(|&x| (x as f64 - mean).powi(2)).sum::<f64>() / n.max(1) as f64;
+
+        Self {
+            runs: n,
+            median_ns,
+            min_ns: nanos.first().copied().unwrap_or(0),
+            stddev_ns: variance.sqrt() as u64,
+        }
+    }
+
+    /// Relative change of the median against `baseline`, e.g. `0.25` for 25%
+    /// slower.
+    pub fn change_from(&self, baseline: &Stats) -> f64 {
+        if baseline.median_ns == 0 {
+            return 0.0;
+        }
+        self.median_ns as f64 / baseline.median_ns as f64 - 1.0
+    }
+}
+
+/// Formats nanoseconds with a unit that keeps the number readable.
+pub struct Nanos(pub u64);
+
+impl fmt::Display for Nanos {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let ns = self.0 as f64;
+        let text = if ns < 1e3 {
+            format!("{:.0}ns", ns)
+        } else if ns < 1e6 {
+            format!("{:.2}µs", ns / 1e3)
+        } else if ns < 1e9 {
+            format!("{:.2}ms", ns / 1e6)
+        } else {
+            format!("{:.2}s", ns / 1e9)
+        };
+        f.pad(&text)
+    }
+}
+
+fn sample(runs: usize, mut f: impl FnMut()) -> Stats {
+    let samples = (0..runs)
+        .map(|_| {
+            let start = Instant::now();
+            f();
+            start.elapsed()
+        })
+        .collect::<Vec<_>>();
+
+    Stats::from_samples(&samples)
+}
+
+/// Times parsing and each implemented part of `puzzle` on `input`
+/// separately, `runs` times each. Phases are named `parse`, `part1` and
+/// `part2`. Each part is solved once first, so that an input it cannot
+/// answer fails here instead of timing how fast the part gives up.
+pub fn bench_day(puzzle: &dyn Puzzle, input: &Input, runs: usize) -> Result<BTreeMap<String, Stats>, String> {
+    let parsed = puzzle
+        .parse_input(&input.text)
+        .map_err(|err| err.render(&input.origin, &input.text))?;
+    for part in 1..=puzzle.parts() {
+        puzzle
+            .solve_part(parsed.as_ref(), part)
+            .map_err(|err| format!("Day {:02} part {}: error: {}\n", puzzle.day(), part, err))?;
+    }
+
+    let mut phases = BTreeMap::new();
+    phases.insert(
+        "parse".to_string(),
+        sample(runs, || {
+            black_box(puzzle.parse_input(black_box(&input.text)).ok());
+        }),
+    );
+
+    for part in 1..=puzzle.parts() {
+        phases.insert(
+            format!("part{}", part),
+            sample(runs, || {
+                black_box(puzzle.solve_part(black_box(parsed.as_ref()), part).ok());
+            }),
+        );
+    }
+
+    Ok(phases)
+}
+
+/// The year of the days in baselines saved before they were keyed by year,
+/// when 2025 was the only one.
+const UNDATED_YEAR: u16 = 2025;
+
+/// Stored timings, keyed by year and day (`2025/day07`) and then phase.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Baseline {
+    pub days: BTreeMap<String, BTreeMap<String, Stats>>,
+}
+
+impl Baseline {
+    /// Loads the baseline at `path`; a missing file is an empty baseline.
+    /// Days saved without a year (`day07`) are moved under
+    /// [`UNDATED_YEAR`], unless that year has timings of its own for them.
+    pub fn load(path: &Path) -> Result<Self, String> {
+        let mut baseline: Self = match std::fs::read_to_string(path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|err| format!("invalid baseline {}: {}", path.display(), err))?,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => return Ok(Self::default()),
+            Err(err) => return Err(format!("cannot read {}: {}", path.display(), err)),
+        };
+
+        let undated = baseline.days.keys().filter(|day| !day.contains('/')).cloned().collect::<Vec<_>>();
+        for day in undated {
+            let phases = baseline.days.remove(&day).expect("key was just listed");
+            baseline.days.entry(format!("{}/{}", UNDATED_YEAR, day)).or_insert(phases);
+        }
+        Ok(baseline)
+    }
+
+    pub fn save(&self, path: &Path) -> Result<(), String> {
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| format!("cannot create {}: {}", parent.display(), err))?;
+        }
+
+        let json = serde_json::to_string_pretty(self).expect("baseline serializes");
+        std::fs::write(path, json + "\n").map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+
+    pub fn get(&self, day: &str, phase: &str) -> Option<&Stats> {
+        self.days.get(day)?.get(phase)
+    }
+}
+
+/// Whether `current` is slower than `baseline` by more than `threshold`
+/// (a fraction, `0.1` for 10%).
+pub fn is_regression(current: &Stats, baseline: &Stats, threshold: f64) -> bool {
+    current.change_from(baseline) > threshold
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn ms(values: &[u64]) -> Vec<Duration> {
+        values.iter().map(|&v| Duration::from_millis(v)).collect()
+    }
+
+    #[test]
+    fn summarizes_samples() {
+        let stats = Stats::from_samples(&ms(&[4, 2, 6, 8]));
+        assert_eq!(stats.runs, 4);
+        assert_eq!(stats.median_ns, 5_000_000);
+        assert_eq!(stats.min_ns, 2_000_000);
+        assert_eq!(stats.stddev_ns, 2_236_067);
+    }
+
+    #[test]
+    fn flags_slowdowns_beyond_threshold() {
+        let baseline = Stats::from_samples(&ms(&[10]));
+        let slightly_slower = Stats::from_samples(&ms(&[11]));
+        let much_slower = Stats::from_samples(&ms(&[13]));
+
+        assert!(!is_regression(&slightly_slower, &baseline, 0.2));
+        assert!(is_regression(&much_slower, &baseline, 0.2));
+        assert!(!is_regression(&baseline, &much_slower, 0.2));
+    }
+
+    #[test]
+    fn refuses_inputs_a_part_cannot_answer() {
+        let puzzle = crate::days::find(2025, 11).unwrap();
+        let input = |text: &str| Input { text: text.to_string(), origin: "test.txt".to_string() };
+
+        let phases = bench_day(puzzle, &input("you: out\nsvr: out\n"), 2).unwrap();
+        assert_eq!(phases.keys().collect::<Vec<_>>(), ["parse", "part1", "part2"]);
+        assert!(phases.values().all(|stats| stats.runs == 2));
+
+        assert_eq!(
+            bench_day(puzzle, &input("svr: out\n"), 2).unwrap_err(),
+            "Day 11 part 1: error: there is no device named `you`\n"
+        );
+        assert!(bench_day(puzzle, &input("you\n"), 2).unwrap_err().starts_with("error: "));
+    }
+
+    #[test]
+    fn moves_days_saved_without_a_year_under_2025() {
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("baseline.json");
+        let stats = |ms: u64| Stats::from_samples(&[Duration::from_millis(ms)]);
+        let phases = |ms: u64| BTreeMap::from([("part1".to_string(), stats(ms))]);
+        let old = Baseline {
+            days: BTreeMap::from([
+                ("day07".to_string(), phases(7)),
+                ("day08".to_string(), phases(8)),
+                ("2025/day08".to_string(), phases(80)),
+            ]),
+        };
+        old.save(&path).unwrap();
+
+        let baseline = Baseline::load(&path).unwrap();
+        assert_eq!(baseline.days.keys().collect::<Vec<_>>(), ["2025/day07", "2025/day08"]);
+        assert_eq!(baseline.get("2025/day07", "part1"), Some(&stats(7)));
+        assert_eq!(baseline.get("2025/day08", "part1"), Some(&stats(80)));
+    }
+
+    #[test]
+    fn formats_durations() {
+        assert_eq!(Nanos(850).to_string(), "850ns");
+        assert_eq!(Nanos(1_500).to_string(), "1.50µs");
+        assert_eq!(Nanos(2_345_678).to_string(), "2.35ms");
+        assert_eq!(Nanos(3_000_000_000).to_string(), "3.00s");
+    }
+}
@@ -0,0 +1,256 @@
+//! Runs one part of one day in a child process, so a part that hangs or
+//! eats memory can be stopped without taking the runner down with it.
+
+use std::fmt;
+use std::io::{Read, Write};
+use std::path::Path;
+use std::process::{Command, Stdio};
+use std::thread;
+use std::time::{Duration, Instant};
+
+use aoc_core::{Input, InputSource, Puzzle};
+
+use crate::bench::Nanos;
+use crate::report::Record;
+
+/// Limits for one part. A part over either is stopped and reported as an
+/// error.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct Budget {
+    /// Wall-clock time, including parsing.
+    pub timeout: Option<Duration>,
+    /// Address space, in bytes.
+    pub memory: Option<u64>,
+}
+
+/// Formats a byte count in binary units.
+pub struct Bytes(pub u64);
+
+impl fmt::Display for Bytes {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let bytes = self.0 as f64;
+        let text = if bytes < 1024.0 {
+            format!("{}B", self.0)
+        } else if bytes < 1024.0 * 1024.0 {
+            format!("{:.1}KiB", bytes / 1024.0)
+        } else if bytes < 1024.0 * 1024.0 * 1024.0 {
+            format!("{:.1}MiB", bytes / (1024.0 * 1024.0))
+        } else {
+            format!("{:.2}GiB", bytes / (1024.0 * 1024.0 * 1024.0))
+        };
+        f.pad(&text)
+    }
+}
+
+/// How a child process ended.
+enum Exit {
+    Code(i32),
+    Signal(i32),
+    TimedOut,
+}
+
+/// What the child left behind.
+struct Finished {
+    exit: Exit,
+    elapsed: Duration,
+    peak_rss: Option<u64>,
+    stdout: Vec<u8>,
+    stderr: Vec<u8>,
+}
+
+/// Runs each of `parts` of `puzzle` with [`run_part`], on the input from
+/// `source`. Every part gets a record, as with [`crate::report::run_day`].
+pub fn run_day(
+    exe: &Path,
+    year: u16,
+    puzzle: &dyn Puzzle,
+    source: &InputSource,
+    day_dir: &Path,
+    parts: &[u8],
+    budget: Budget,
+) -> Vec<Record> {
+    let day = puzzle.day();
+
+    match source.load(day_dir) {
+        Ok(input) => parts.iter().map(|&part| run_part(exe, year, day, part, &input, budget)).collect(),
+        Err(err) => {
+            let origin = source
+                .path(day_dir)
+                .map_or("<stdin>".to_string(), |path| path.display().to_string());
+            parts.iter().map(|&part| Record::failed(year, day, part, &origin, err.to_string())).collect()
+        }
+    }
+}
+
+/// Runs `part` of `day` of `year` on `input` in a child process of the
+/// runner binary at `exe`, within `budget`. The record carries the child's
+/// wall-clock time and peak resident memory; a child that runs out of
+/// either budget, or dies, gets an error record instead of an answer.
+pub fn run_part(exe: &Path, year: u16, day: u8, part: u8, input: &Input, budget: Budget) -> Record {
+    let mut command = Command::new(exe);
+    command
+        .args(["--year", &year.to_string(), "run", &day.to_string(), "--part", &part.to_string()])
+        .args(["--format", "json", "--no-cache", "--input", "-"]);
+
+    let failed = |error: String| Record::failed(year, day, part, &input.origin, error);
+
+    let finished = match spawn(command, input.text.as_bytes(), budget) {
+        Ok(finished) => finished,
+        Err(err) => return failed(format!("cannot run the part: {}", err)),
+    };
+    let measured = |record: Record| Record {
+        elapsed_ns: Some(finished.elapsed.as_nanos() as u64),
+        peak_rss: finished.peak_rss,
+        ..record
+    };
+
+    let stderr = String::from_utf8_lossy(&finished.stderr);
+    let out_of_memory = stderr.contains("memory allocation of");
+    // Anything else the part printed, such as a trace table, is passed on.
+    if !out_of_memory {
+        eprint!("{}", stderr);
+    }
+
+    let error = match finished.exit {
+        Exit::TimedOut => {
+            let timeout = budget.timeout.unwrap_or_default();
+            format!("timed out after {}", Nanos(timeout.as_nanos() as u64))
+        }
+        _ if out_of_memory => {
+            let limit = budget.memory.unwrap_or_default();
+            format!("ran out of memory under the {} limit", Bytes(limit))
+        }
+        Exit::Signal(signal) => match budget.memory {
+            Some(limit) => format!("killed by signal {}; the {} memory limit may be too low", signal, Bytes(limit)),
+            None => format!("killed by signal {}", signal),
+        },
+        Exit::Code(code) => match serde_json::from_slice::<Vec<Record>>(&finished.stdout) {
+            Ok(records) => match records.into_iter().next() {
+                Some(record) => {
+                    return measured(Record {
+                        input: input.origin.clone(),
+                        ..record
+                    })
+                }
+                None => format!("exited with status {} without an answer", code),
+            },
+            Err(_) => format!("exited with status {} without an answer", code),
+        },
+    };
+
+    measured(failed(error))
+}
+
+/// Starts `command` within `budget`, feeds it `stdin` and waits for it,
+/// killing it once the timeout passes.
+fn spawn(mut command: Command, stdin: &[u8], budget: Budget) -> std::io::Result<Finished> {
+    use std::os::unix::process::CommandExt;
+
+    command.stdin(Stdio::piped()).stdout(Stdio::piped()).stderr(Stdio::piped());
+    if let Some(bytes) = budget.memory {
+        let limit = libc::rlimit {
+            rlim_cur: bytes as libc::rlim_t,
+            rlim_max: bytes as libc::rlim_t,
+        };
+        // SAFETY: only calls setrlimit, which is async-signal-safe, between
+        // fork and exec.
+        unsafe {
+            command.pre_exec(move || match libc::setrlimit(libc::RLIMIT_AS, &limit) {
+                0 => Ok(()),
+                _ => Err(std::io::Error::last_os_error()),
+            });
+        }
+    }
+
+    let start = Instant::now();
+    let mut child = command.spawn()?;
+    let pid = child.id() as libc::pid_t;
+
+    // Pipes are drained on their own threads so a chatty child never blocks
+    // on a full one while the runner waits for it.
+    let mut input = child.stdin.take().expect("stdin is piped");
+    let stdin = stdin.to_vec();
+    let writer = thread::spawn(move || {
+        // A child that exits without reading its input is not an error here.
+        let _ = input.write_all(&stdin);
+    });
+    let drain = |pipe: Option<Box<dyn Read + Send>>| {
+        thread::spawn(move || {
+            let mut bytes = Vec::new();
+            if let Some(mut pipe) = pipe {
+                let _ = pipe.read_to_end(&mut bytes);
+            }
+            bytes
+        })
+    };
+    let stdout = drain(child.stdout.take().map(|pipe| Box::new(pipe) as Box<dyn Read + Send>));
+    let stderr = drain(child.stderr.take().map(|pipe| Box::new(pipe) as Box<dyn Read + Send>));
+
+    let mut timed_out = false;
+    let (status, usage) = loop {
+        let flags = if timed_out { 0 } else { libc::WNOHANG };
+        match wait(pid, flags)? {
+            Some(done) => break done,
+            None if budget.timeout.is_some_and(|timeout| start.elapsed() >= timeout) => {
+                timed_out = true;
+                child.kill()?;
+            }
+            None => thread::sleep(Duration::from_millis(1)),
+        }
+    };
+    let elapsed = start.elapsed();
+
+    let _ = writer.join();
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let exit = if timed_out {
+        Exit::TimedOut
+    } else if libc::WIFSIGNALED(status) {
+        Exit::Signal(libc::WTERMSIG(status))
+    } else {
+        Exit::Code(libc::WEXITSTATUS(status))
+    };
+    let peak_rss = u64::try_from(usage.ru_maxrss).ok().map(|peak| peak.saturating_mul(MAXRSS_UNIT));
+
+    Ok(Finished { exit, elapsed, peak_rss, stdout, stderr })
+}
+
+/// Bytes in a unit of `ru_maxrss`: macOS reports the peak in bytes, Linux
+/// and the BSDs in kilobytes.
+const MAXRSS_UNIT: u64 = if cfg!(target_vendor = "apple") { 1 } else { 1024 };
+
+/// Reaps `pid` if it has exited, along with its resource usage. `std`'s own
+/// `wait` does not report usage, so the child is reaped here instead.
+fn wait(pid: libc::pid_t, flags: libc::c_int) -> std::io::Result<Option<(libc::c_int, libc::rusage)>> {
+    let mut status = 0;
+    // SAFETY: rusage is plain data that wait4 fills in.
+    let mut usage = unsafe { std::mem::zeroed::<libc::rusage>() };
+
+    loop {
+        // SAFETY: status and usage point to live, writable values.
+        match unsafe { libc::wait4(pid, &mut status, flags, &mut usage) } {
+            0 => return Ok(None),
+            -1 => {
+                let err = std::io::Error::last_os_error();
+                if err.kind() != std::io::ErrorKind::Interrupted {
+                    return Err(err);
+                }
+            }
+            _ => return Ok(Some((status, usage))),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn formats_bytes_in_binary_units() {
+        assert_eq!(Bytes(512).to_string(), "512B");
+        assert_eq!(Bytes(1536).to_string(), "1.5KiB");
+        assert_eq!(Bytes(64 * 1024 * 1024).to_string(), "64.0MiB");
+        assert_eq!(Bytes(3 * 1024 * 1024 * 1024).to_string(), "3.00GiB");
+    }
+}
@@ -0,0 +1,385 @@
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use aoc_core::{Input, ParseError, Puzzle};
+use serde::{Deserialize, Serialize};
+use sha2::{Digest, Sha256};
+
+use crate::check::panic_message;
+
+/// A hex SHA-256 of an input, the part of a cache key that follows the input
+/// wherever it is read from.
+pub fn hash(input: &str) -> String {
+    Sha256::digest(input.as_bytes()).iter().map(|byte| format!("{:02x}", byte)).collect()
+}
+
+include!(concat!(env!("OUT_DIR"), "/fingerprints.rs"));
+
+/// A hex SHA-256 of the source the runner was built with for `day`: the day
+/// crate and the `aoc-core` code it shares. Editing either drops the day's
+/// cached answers, so a solver change needs no `VERSION` bump.
+pub fn source(year: u16, day: u8) -> &'static str {
+    FINGERPRINTS
+        .iter()
+        .find(|&&(known_year, known_day, _)| (known_year, known_day) == (year, day))
+        .map_or("", |&(_, _, hash)| hash)
+}
+
+/// One answer, valid for the input with this hash under this solver version
+/// and source.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Entry {
+    pub part: u8,
+    pub version: u32,
+    /// Empty in caches written before sources were hashed, so those answers
+    /// are never reused.
+    #[serde(default)]
+    pub source: String,
+    pub input: String,
+    pub answer: String,
+}
+
+/// Every answer cached for one day, stored as JSON under `dir/YYYY`.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct Cache {
+    pub entries: Vec<Entry>,
+}
+
+impl Cache {
+    pub fn path(dir: &Path, year: u16, day: u8) -> PathBuf {
+        dir.join(year.to_string()).join(format!("day{:02}.json", day))
+    }
+
+    /// Loads the day's cache; a day without one has no answers.
+    pub fn load(dir: &Path, year: u16, day: u8) -> Result<Self, String> {
+        let path = Self::path(dir, year, day);
+
+        match std::fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|err| format!("invalid {}: {}", path.display(), err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("cannot read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn save(&self, dir: &Path, year: u16, day: u8) -> Result<(), String> {
+        let path = Self::path(dir, year, day);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| format!("cannot create {}: {}", parent.display(), err))?;
+        }
+
+        let json = serde_json::to_string_pretty(self).expect("cache serializes");
+        std::fs::write(&path, json + "\n").map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+
+    pub fn get(&self, part: u8, version: u32, source: &str, input: &str) -> Option<&str> {
+        self.entries
+            .iter()
+            .find(|entry| entry.part == part && entry.version == version && entry.source == source && entry.input == input)
+            .map(|entry| entry.answer.as_str())
+    }
+
+    /// Stores an answer, replacing whatever an earlier solver version or
+    /// source gave for the same part and input.
+    pub fn insert(&mut self, part: u8, version: u32, source: &str, input: &str, answer: &str) {
+        self.entries.retain(|entry| entry.part != part || entry.input != input);
+        self.entries.push(Entry {
+            part,
+            version,
+            source: source.to_string(),
+            input: input.to_string(),
+            answer: answer.to_string(),
+        });
+    }
+}
+
+/// Deletes the cached answers of `day`, or of every day of `year`. Returns
+/// how many days had answers to forget.
+pub fn clear(dir: &Path, year: u16, day: Option<u8>) -> Result<usize, String> {
+    let paths = match day {
+        Some(day) => vec![Cache::path(dir, year, day)],
+        None => match std::fs::read_dir(dir.join(year.to_string())) {
+            Ok(entries) => entries.filter_map(|entry| entry.ok().map(|entry| entry.path())).collect(),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Vec::new(),
+            Err(err) => return Err(format!("cannot read {}: {}", dir.display(), err)),
+        },
+    };
+
+    let mut cleared = 0;
+    for path in paths {
+        match std::fs::remove_file(&path) {
+            Ok(()) => cleared += 1,
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => {}
+            Err(err) => return Err(format!("cannot remove {}: {}", path.display(), err)),
+        }
+    }
+    Ok(cleared)
+}
+
+/// Where a run reads and stores answers: the cache directory, or nowhere.
+#[derive(Debug, Clone, Copy)]
+pub struct Answers<'a> {
+    pub dir: Option<&'a Path>,
+}
+
+impl Answers<'_> {
+    /// Loads the day's cache, or an empty one when caching is off or the
+    /// cache cannot be read.
+    pub fn load(&self, year: u16, day: u8) -> Cache {
+        let Some(dir) = self.dir else { return Cache::default() };
+
+        Cache::load(dir, year, day).unwrap_or_else(|err| {
+            eprintln!("warning: {}", err);
+            Cache::default()
+        })
+    }
+
+    /// Saves the day's cache. Failing to is only worth a warning, since the
+    /// answers themselves are fine.
+    pub fn save(&self, year: u16, day: u8, cache: &Cache) {
+        if let Some(dir) = self.dir {
+            if let Err(err) = cache.save(dir, year, day) {
+                eprintln!("warning: {}", err);
+            }
+        }
+    }
+
+    /// Answers each of `parts` of `puzzle`, a day of `year`: from the cache
+    /// where it can, otherwise by parsing `input`, only once some part has
+    /// to be solved, and solving, timing both. New answers are cached;
+    /// errors are not. Panics inside the solution are caught and reported
+    /// like errors.
+    pub fn solve(&self, year: u16, puzzle: &dyn Puzzle, input: &str, parts: &[u8]) -> Result<Solved, Unparsed> {
+        let day = puzzle.day();
+        let version = puzzle.version();
+        let source = source(year, day);
+        let key = hash(input);
+        let mut cache = self.load(year, day);
+
+        let cached = parts
+            .iter()
+            .map(|&part| cache.get(part, version, source, &key).map(str::to_string))
+            .collect::<Vec<_>>();
+        if cached.iter().all(Option::is_some) {
+            let answers = cached.into_iter().flatten().map(Answer::Cached).collect();
+            return Ok(Solved { parse_ns: None, answers });
+        }
+
+        let start = Instant::now();
+        let parsed = panic::catch_unwind(AssertUnwindSafe(|| puzzle.parse_input(input)));
+        let parse_ns = start.elapsed().as_nanos() as u64;
+        let parsed = match parsed {
+            Ok(Ok(parsed)) => parsed,
+            Ok(Err(err)) => return Err(Unparsed::Invalid { err, parse_ns }),
+            Err(payload) => return Err(Unparsed::Panicked(panic_message(payload))),
+        };
+
+        let answers = parts
+            .iter()
+            .zip(cached)
+            .map(|(&part, cached)| {
+                if let Some(answer) = cached {
+                    return Answer::Cached(answer);
+                }
+                if part > puzzle.parts() {
+                    return Answer::NotImplemented;
+                }
+
+                let start = Instant::now();
+                let answer = panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve_part(parsed.as_ref(), part)));
+                let solve_ns = start.elapsed().as_nanos() as u64;
+
+                let answer = match answer {
+                    Ok(Ok(answer)) => {
+                        cache.insert(part, version, source, &key, &answer);
+                        Ok(answer)
+                    }
+                    Ok(Err(err)) => Err(format!("error: {}", err)),
+                    Err(payload) => Err(format!("panicked: {}", panic_message(payload))),
+                };
+                Answer::Solved { answer, solve_ns }
+            })
+            .collect();
+
+        self.save(year, day, &cache);
+        Ok(Solved { parse_ns: Some(parse_ns), answers })
+    }
+
+    /// Like [`aoc_core::solution::solve_parts`], but through [`Answers::solve`],
+    /// marking the parts answered from the cache `(cached)`. An input that
+    /// does not parse gives the error to print for it.
+    pub fn solve_parts(
+        &self,
+        year: u16,
+        puzzle: &dyn Puzzle,
+        input: &Input,
+        parts: &[u8],
+    ) -> Result<Vec<Result<String, String>>, String> {
+        let day = puzzle.day();
+        let solved = self.solve(year, puzzle, &input.text, parts).map_err(|unparsed| match unparsed {
+            Unparsed::Invalid { err, .. } => err.render(&input.origin, &input.text),
+            Unparsed::Panicked(message) => format!("Day {:02}: parse panicked: {}\n", day, message),
+        })?;
+
+        Ok(parts
+            .iter()
+            .zip(solved.answers)
+            .map(|(&part, answer)| match answer {
+                Answer::Cached(answer) => Ok(format!("Day {:02} part {}: {} (cached)", day, part, answer)),
+                Answer::NotImplemented => Ok(format!("Day {:02} part {}: not implemented", day, part)),
+                Answer::Solved { answer: Ok(answer), .. } => Ok(format!("Day {:02} part {}: {}", day, part, answer)),
+                Answer::Solved { answer: Err(err), .. } => Err(format!("Day {:02} part {}: {}", day, part, err)),
+            })
+            .collect())
+    }
+}
+
+/// Every requested part's answer from [`Answers::solve`], in order.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Solved {
+    /// How long parsing took, if any part had to be solved.
+    pub parse_ns: Option<u64>,
+    pub answers: Vec<Answer>,
+}
+
+/// How [`Answers::solve`] answered one part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Answer {
+    /// From the cache, without solving.
+    Cached(String),
+    /// The day does not solve this part yet.
+    NotImplemented,
+    /// Solved just now, in `solve_ns`: the answer, or why there is none.
+    Solved { answer: Result<String, String>, solve_ns: u64 },
+}
+
+/// Why [`Answers::solve`] answered no part at all.
+#[derive(Debug)]
+pub enum Unparsed {
+    /// The input does not parse, as found out in `parse_ns`.
+    Invalid { err: ParseError, parse_ns: u64 },
+    /// Parsing panicked, with this message.
+    Panicked(String),
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn input(text: &str) -> Input {
+        Input { text: text.to_string(), origin: "test.txt".to_string() }
+    }
+
+    #[test]
+    fn hashes_inputs_in_hex() {
+        assert_eq!(hash(""), "e3b0c44298fc1c149afbf4c8996fb92427ae41e4649b934ca495991b7852b855");
+        assert_ne!(hash("L68\n"), hash("L68"));
+    }
+
+    #[test]
+    fn keys_answers_by_part_version_source_and_input() {
+        let mut cache = Cache::default();
+        cache.insert(1, 1, "src", "abc", "42");
+        cache.insert(2, 1, "src", "abc", "7");
+
+        assert_eq!(cache.get(1, 1, "src", "abc"), Some("42"));
+        assert_eq!(cache.get(1, 2, "src", "abc"), None);
+        assert_eq!(cache.get(1, 1, "edited", "abc"), None);
+        assert_eq!(cache.get(1, 1, "src", "def"), None);
+
+        cache.insert(1, 2, "src", "abc", "43");
+        assert_eq!(cache.get(1, 1, "src", "abc"), None);
+        assert_eq!(cache.get(1, 2, "src", "abc"), Some("43"));
+        cache.insert(1, 2, "edited", "abc", "44");
+        assert_eq!(cache.get(1, 2, "src", "abc"), None);
+        assert_eq!(cache.entries.len(), 2);
+    }
+
+    #[test]
+    fn hashes_the_source_of_every_day() {
+        let days = days::year(2025).unwrap().days;
+        let sources = days.iter().map(|puzzle| source(2025, puzzle.day())).collect::<Vec<_>>();
+
+        assert!(sources.iter().all(|source| source.len() == 64), "{:?}", sources);
+        assert!(sources.iter().enumerate().all(|(i, source)| !sources[..i].contains(source)));
+        assert_eq!(source(2025, 26), "");
+    }
+
+    #[test]
+    fn drops_answers_cached_before_sources_were_hashed() {
+        let json = r#"{"entries": [{"part": 1, "version": 1, "input": "abc", "answer": "42"}]}"#;
+        let cache: Cache = serde_json::from_str(json).unwrap();
+
+        assert_eq!(cache.get(1, 1, source(2025, 1), "abc"), None);
+    }
+
+    #[test]
+    fn answers_again_from_the_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let answers = Answers { dir: Some(dir.path()) };
+        let puzzle = days::find(2025, 1).unwrap();
+        let input = input("L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n");
+
+        let first = answers.solve_parts(2025, puzzle, &input, &[1, 2]).unwrap();
+        assert_eq!(first, vec![Ok("Day 01 part 1: 3".to_string()), Ok("Day 01 part 2: 6".to_string())]);
+
+        let second = answers.solve_parts(2025, puzzle, &input, &[1, 2]).unwrap();
+        assert_eq!(second, vec![Ok("Day 01 part 1: 3 (cached)".to_string()), Ok("Day 01 part 2: 6 (cached)".to_string())]);
+
+        assert_eq!(clear(dir.path(), 2025, None).unwrap(), 1);
+        let third = answers.solve_parts(2025, puzzle, &input, &[1]).unwrap();
+        assert_eq!(third, vec![Ok("Day 01 part 1: 3".to_string())]);
+    }
+
+    #[test]
+    fn solves_only_the_parts_not_cached() {
+        let dir = tempfile::tempdir().unwrap();
+        let answers = Answers { dir: Some(dir.path()) };
+        let puzzle = days::find(2025, 1).unwrap();
+        let input = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82\n";
+
+        let first = answers.solve(2025, puzzle, input, &[1]).unwrap();
+        assert!(first.parse_ns.is_some());
+        assert!(matches!(&first.answers[..], [Answer::Solved { answer: Ok(answer), .. }] if answer == "3"));
+
+        let second = answers.solve(2025, puzzle, input, &[1, 2, 3]).unwrap();
+        assert!(second.parse_ns.is_some());
+        assert!(matches!(
+            &second.answers[..],
+            [Answer::Cached(cached), Answer::Solved { answer: Ok(solved), .. }, Answer::NotImplemented]
+                if cached == "3" && solved == "6"
+        ));
+    }
+
+    #[test]
+    fn does_not_parse_a_fully_cached_input() {
+        let dir = tempfile::tempdir().unwrap();
+        let answers = Answers { dir: Some(dir.path()) };
+        let puzzle = days::find(2025, 1).unwrap();
+
+        let mut cache = Cache::default();
+        cache.insert(1, puzzle.version(), source(2025, 1), &hash("not a rotation"), "99");
+        answers.save(2025, 1, &cache);
+
+        assert_eq!(
+            answers.solve_parts(2025, puzzle, &input("not a rotation"), &[1]).unwrap(),
+            vec![Ok("Day 01 part 1: 99 (cached)".to_string())]
+        );
+        assert!(answers.solve_parts(2025, puzzle, &input("not a rotation"), &[1, 2]).is_err());
+    }
+
+    #[test]
+    fn does_not_cache_errors() {
+        let dir = tempfile::tempdir().unwrap();
+        let answers = Answers { dir: Some(dir.path()) };
+        let puzzle = days::find(2025, 11).unwrap();
+
+        for _ in 0..2 {
+            assert_eq!(
+                answers.solve_parts(2025, puzzle, &input("svr: out\n"), &[1]).unwrap(),
+                vec![Err("Day 11 part 1: error: there is no device named `you`".to_string())]
+            );
+        }
+    }
+}
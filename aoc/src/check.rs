@@ -0,0 +1,91 @@
+use std::any::Any;
+use std::fmt;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::Path;
+
+use aoc_core::answers::{ExpectedAnswers, Manifest};
+use aoc_core::Puzzle;
+
+/// The outcome of checking one part against one listed input.
+#[derive(Debug, Clone)]
+pub struct CheckResult {
+    pub day: u8,
+    pub file: String,
+    pub part: u8,
+    pub expected: String,
+    /// The answer, or why none was produced.
+    pub actual: Result<String, String>,
+}
+
+impl CheckResult {
+    pub fn passed(&self) -> bool {
+        self.actual.as_deref() == Ok(self.expected.as_str())
+    }
+}
+
+impl fmt::Display for CheckResult {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} {} part {}: ", self.day, self.file, self.part)?;
+
+        match &self.actual {
+            Ok(actual) if self.passed() => write!(f, "ok ({})", actual),
+            Ok(actual) => write!(f, "FAILED, expected {}, got {}", self.expected, actual),
+            Err(err) => write!(f, "FAILED, expected {}, {}", self.expected, err),
+        }
+    }
+}
+
+pub(crate) fn panic_message(payload: Box<dyn Any + Send>) -> String {
+    if let Some(message) = payload.downcast_ref::<&str>() {
+        message.to_string()
+    } else if let Some(message) = payload.downcast_ref::<String>() {
+        message.clone()
+    } else {
+        "unknown panic".to_string()
+    }
+}
+
+/// Runs every part listed in the day's answers manifest and compares the
+/// results. Panics inside the solution are reported as failures.
+pub fn check_day(puzzle: &dyn Puzzle, day_dir: &Path) -> Result<Vec<CheckResult>, String> {
+    let manifest = Manifest::load(day_dir)?;
+    Ok(check_inputs(puzzle, day_dir, &manifest.inputs))
+}
+
+/// Like [`check_day`], for only the `listed` inputs of the manifest.
+pub fn check_inputs(puzzle: &dyn Puzzle, day_dir: &Path, listed: &[ExpectedAnswers]) -> Vec<CheckResult> {
+    let mut results = vec![];
+
+    for listed in listed {
+        let parts = (1..=2u8).filter_map(|part| listed.part(part).map(|expected| (part, expected.to_string())));
+
+        let parsed = match std::fs::read_to_string(day_dir.join(&listed.file)) {
+            Ok(text) => match panic::catch_unwind(AssertUnwindSafe(|| puzzle.parse_input(&text))) {
+                Ok(Ok(parsed)) => Ok(parsed),
+                Ok(Err(err)) => Err(format!("parse error at {}:{}: {}", err.line, err.column, err)),
+                Err(payload) => Err(format!("parse panicked: {}", panic_message(payload))),
+            },
+            Err(err) => Err(format!("cannot read input: {}", err)),
+        };
+
+        for (part, expected) in parts {
+            let actual = match &parsed {
+                Ok(parsed) => match panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve_part(parsed.as_ref(), part))) {
+                    Ok(answer) => answer.map_err(|err| format!("error: {}", err)),
+                    Err(payload) => Err(format!("panicked: {}", panic_message(payload))),
+                },
+                Err(err) => Err(err.clone()),
+            };
+
+            results.push(CheckResult {
+                day: puzzle.day(),
+                file: listed.file.clone(),
+                part,
+                expected,
+                actual,
+            });
+        }
+    }
+
+    results
+}
@@ -0,0 +1,179 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+use crate::submit::Verdict;
+
+pub const DEFAULT_BASE_URL: &str = "https://adventofcode.com";
+
+/// Sent with every request, as the site asks automated tools to identify
+/// themselves.
+const USER_AGENT: &str = "github.com/Jandhi/AdventOfCode2025 aoc runner";
+
+/// Enforces a minimum delay between requests, across runs of the tool, by
+/// keeping the time of the last request in a stamp file.
+#[derive(Debug, Clone)]
+pub struct Throttle {
+    pub min_interval: Duration,
+    pub stamp: PathBuf,
+}
+
+impl Throttle {
+    fn last_request(&self) -> Option<SystemTime> {
+        let millis = std::fs::read_to_string(&self.stamp).ok()?.trim().parse().ok()?;
+        Some(UNIX_EPOCH + Duration::from_millis(millis))
+    }
+
+    /// Sleeps until `min_interval` has passed since the last request, then
+    /// records a new request.
+    pub fn wait(&self) {
+        if let Some(last) = self.last_request() {
+            let elapsed = SystemTime::now().duration_since(last).unwrap_or_default();
+            if elapsed < self.min_interval {
+                thread::sleep(self.min_interval - elapsed);
+            }
+        }
+
+        let now = SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default();
+        if let Some(parent) = self.stamp.parent() {
+            let _ = std::fs::create_dir_all(parent);
+        }
+        let _ = std::fs::write(&self.stamp, now.as_millis().to_string());
+    }
+}
+
+#[derive(Debug)]
+pub enum ClientError {
+    NoSession,
+    /// The site answered with an error status.
+    Status { url: String, code: u16, body: String },
+    Network { url: String, reason: String },
+    Io { path: PathBuf, reason: String },
+    /// A submission page without a recognizable verdict.
+    NoVerdict { url: String },
+}
+
+impl fmt::Display for ClientError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ClientError::NoSession => write!(f, "no session token: set AOC_SESSION to your adventofcode.com session cookie"),
+            ClientError::Status { url, code: 404, .. } => write!(f, "{} not found (is the puzzle unlocked yet?)", url),
+            ClientError::Status { url, code: 400 | 500, .. } => {
+                write!(f, "{} rejected the request (is the session token still valid?)", url)
+            }
+            ClientError::Status { url, code, body } => write!(f, "{} answered {}: {}", url, code, body.trim()),
+            ClientError::Network { url, reason } => write!(f, "cannot reach {}: {}", url, reason),
+            ClientError::Io { path, reason } => write!(f, "cannot write {}: {}", path.display(), reason),
+            ClientError::NoVerdict { url } => write!(f, "{} answered without a verdict", url),
+        }
+    }
+}
+
+impl std::error::Error for ClientError {}
+
+/// Where a fetched input came from.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Fetched {
+    /// The input was already on disk, so nothing was requested.
+    Cached(PathBuf),
+    Downloaded(PathBuf),
+}
+
+/// Talks to the Advent of Code site, or anything serving the same paths.
+#[derive(Debug, Clone)]
+pub struct Client {
+    pub base_url: String,
+    pub session: Option<String>,
+    pub year: u16,
+    pub throttle: Throttle,
+}
+
+impl Client {
+    /// A client for `year` configured from `AOC_SESSION` and `AOC_BASE_URL`,
+    /// throttled through a stamp file in `state_dir`.
+    pub fn from_env(state_dir: &Path, year: u16) -> Self {
+        Self {
+            base_url: std::env::var("AOC_BASE_URL").unwrap_or_else(|_| DEFAULT_BASE_URL.to_string()),
+            session: std::env::var("AOC_SESSION").ok().filter(|session| !session.trim().is_empty()),
+            year,
+            throttle: Throttle {
+                min_interval: Duration::from_secs(5),
+                stamp: state_dir.join("last-request"),
+            },
+        }
+    }
+
+    fn url(&self, day: u8, suffix: &str) -> String {
+        format!("{}/{}/day/{}{}", self.base_url.trim_end_matches('/'), self.year, day, suffix)
+    }
+
+    fn session(&self) -> Result<&str, ClientError> {
+        self.session.as_deref().map(str::trim).ok_or(ClientError::NoSession)
+    }
+
+    fn request(&self, method: &str, url: &str) -> Result<ureq::Request, ClientError> {
+        Ok(ureq::request(method, url)
+            .set("Cookie", &format!("session={}", self.session()?))
+            .set("User-Agent", USER_AGENT))
+    }
+
+    /// Sends `request`, with `form` as its body if given, and reads the
+    /// response body. Error statuses become errors.
+    fn send(&self, url: &str, request: ureq::Request, form: Option<&[(&str, &str)]>) -> Result<String, ClientError> {
+        self.throttle.wait();
+
+        let response = match form {
+            Some(form) => request.send_form(form),
+            None => request.call(),
+        };
+
+        match response {
+            Ok(response) => response.into_string().map_err(|err| ClientError::Network {
+                url: url.to_string(),
+                reason: err.to_string(),
+            }),
+            Err(ureq::Error::Status(code, response)) => Err(ClientError::Status {
+                url: url.to_string(),
+                code,
+                body: response.into_string().unwrap_or_default(),
+            }),
+            Err(err) => Err(ClientError::Network {
+                url: url.to_string(),
+                reason: err.to_string(),
+            }),
+        }
+    }
+
+    /// Downloads the input for `day` into `day_dir/input.txt`, unless a
+    /// non-empty input is already there.
+    pub fn fetch_input(&self, day: u8, day_dir: &Path) -> Result<Fetched, ClientError> {
+        let path = day_dir.join("input.txt");
+        if std::fs::metadata(&path).is_ok_and(|meta| meta.len() > 0) {
+            return Ok(Fetched::Cached(path));
+        }
+
+        let url = self.url(day, "/input");
+        let request = self.request("GET", &url)?;
+        let body = self.send(&url, request, None)?;
+
+        // Write through a temporary file so an interrupted download never
+        // leaves a partial input behind that would count as cached.
+        let partial = day_dir.join("input.txt.partial");
+        std::fs::write(&partial, body)
+            .and_then(|()| std::fs::rename(&partial, &path))
+            .map_err(|err| ClientError::Io { path: path.clone(), reason: err.to_string() })?;
+
+        Ok(Fetched::Downloaded(path))
+    }
+
+    /// Submits `answer` to `part` of `day` and reads the verdict from the
+    /// page that comes back.
+    pub fn submit(&self, day: u8, part: u8, answer: &str) -> Result<Verdict, ClientError> {
+        let url = self.url(day, "/answer");
+        let request = self.request("POST", &url)?;
+        let body = self.send(&url, request, Some(&[("level", &part.to_string()), ("answer", answer)]))?;
+
+        Verdict::from_response(&body).ok_or(ClientError::NoVerdict { url })
+    }
+}
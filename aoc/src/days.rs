@@ -0,0 +1,46 @@
+use aoc_core::Puzzle;
+
+/// One event year and its days, in order.
+pub struct Year {
+    pub year: u16,
+    pub days: &'static [&'static dyn Puzzle],
+}
+
+impl Year {
+    pub fn find(&self, day: u8) -> Option<&'static dyn Puzzle> {
+        self.days.iter().copied().find(|puzzle| puzzle.day() == day)
+    }
+}
+
+/// Every year known to the runner, oldest first.
+pub static YEARS: &[Year] = &[
+    Year { year: 2025, days: Y2025 },
+];
+
+/// The year the runner uses when none is given: the most recent one.
+pub fn latest() -> u16 {
+    YEARS.last().expect("the workspace has at least one year").year
+}
+
+pub fn year(year: u16) -> Option<&'static Year> {
+    YEARS.iter().find(|known| known.year == year)
+}
+
+pub fn find(year: u16, day: u8) -> Option<&'static dyn Puzzle> {
+    self::year(year)?.find(day)
+}
+
+static Y2025: &[&dyn Puzzle] = &[
+    &aoc2025_day01::Day01,
+    &aoc2025_day02::Day02,
+    &aoc2025_day03::Day03,
+    &aoc2025_day04::Day04,
+    &aoc2025_day05::Day05,
+    &aoc2025_day06::Day06,
+    &aoc2025_day07::Day07,
+    &aoc2025_day08::Day08,
+    &aoc2025_day09::Day09,
+    &aoc2025_day10::Day10,
+    &aoc2025_day11::Day11,
+    &aoc2025_day12::Day12,
+];
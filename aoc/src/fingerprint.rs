@@ -0,0 +1,74 @@
+//! A hash of the source a day's answers come from. The build script hashes
+//! every day as the runner is compiled, so editing a solver, or the shared
+//! code in `aoc-core`, drops the answers cached before the edit.
+
+use std::path::{Path, PathBuf};
+
+use sha2::{Digest, Sha256};
+
+/// Every file under `path`, or `path` itself if it is a file, sorted so the
+/// order does not depend on the file system.
+fn files(path: &Path) -> std::io::Result<Vec<PathBuf>> {
+    if !path.is_dir() {
+        return Ok(vec![path.to_path_buf()]);
+    }
+
+    let mut files = vec![];
+    let mut dirs = vec![path.to_path_buf()];
+    while let Some(dir) = dirs.pop() {
+        for entry in std::fs::read_dir(&dir)? {
+            let path = entry?.path();
+            if path.is_dir() {
+                dirs.push(path);
+            } else {
+                files.push(path);
+            }
+        }
+    }
+    files.sort();
+    Ok(files)
+}
+
+/// A hex SHA-256 of the files under each of `paths`: their names relative
+/// to that path, and their contents.
+pub fn hash(paths: &[&Path]) -> std::io::Result<String> {
+    let mut hasher = Sha256::new();
+
+    for &root in paths {
+        for file in files(root)? {
+            let name = file.strip_prefix(root).unwrap_or(&file);
+            hasher.update(name.to_string_lossy().as_bytes());
+            hasher.update([0]);
+            hasher.update(std::fs::read(&file)?);
+            hasher.update([0]);
+        }
+    }
+
+    Ok(hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn changes_with_any_source_file() {
+        let dir = tempfile::tempdir().unwrap();
+        let src = dir.path().join("src");
+        std::fs::create_dir_all(src.join("grid")).unwrap();
+        std::fs::write(src.join("lib.rs"), "fn part1() {}").unwrap();
+        std::fs::write(src.join("grid").join("mod.rs"), "fn parse() {}").unwrap();
+        let manifest = dir.path().join("Cargo.toml");
+        std::fs::write(&manifest, "[package]").unwrap();
+
+        let before = hash(&[&src, &manifest]).unwrap();
+        assert_eq!(hash(&[&src, &manifest]).unwrap(), before);
+
+        std::fs::write(src.join("grid").join("mod.rs"), "fn parse() { }").unwrap();
+        let edited = hash(&[&src, &manifest]).unwrap();
+        assert_ne!(edited, before);
+
+        std::fs::rename(src.join("grid").join("mod.rs"), src.join("grid.rs")).unwrap();
+        assert_ne!(hash(&[&src, &manifest]).unwrap(), edited);
+    }
+}
@@ -0,0 +1,49 @@
+//! The runner's library half: the table of days and the tooling built on it,
+//! shared by the `aoc` binary and the workspace tests.
+
+pub mod animate;
+pub mod bench;
+#[cfg(unix)]
+pub mod budget;
+pub mod cache;
+pub mod check;
+pub mod client;
+pub mod days;
+#[cfg(test)]
+mod fingerprint;
+pub mod repl;
+pub mod report;
+pub mod scaffold;
+pub mod stats;
+pub mod submit;
+pub mod watch;
+
+use std::path::{Path, PathBuf};
+
+/// The workspace directory holding the `YYYY/dayNN` crates.
+pub fn workspace_root() -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .parent()
+        .expect("runner crate lives inside the workspace")
+        .to_path_buf()
+}
+
+/// Local state of the tool itself, such as request throttling.
+pub fn state_dir() -> PathBuf {
+    workspace_root().join(".aoc")
+}
+
+/// Answers the runner has already computed, keyed by input, solver
+/// version and source.
+pub fn cache_dir() -> PathBuf {
+    state_dir().join("cache")
+}
+
+/// The directory holding one event year's day crates.
+pub fn year_dir(year: u16) -> PathBuf {
+    workspace_root().join(year.to_string())
+}
+
+pub fn day_dir(year: u16, day: u8) -> PathBuf {
+    year_dir(year).join(format!("day{:02}", day))
+}
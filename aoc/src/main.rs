@@ -0,0 +1,761 @@
+use std::path::PathBuf;
+use std::process::ExitCode;
+use std::time::Duration;
+
+use aoc::animate::{self, Fit, Keyboard, NoControls, Player};
+use aoc::bench::{self, Baseline, Nanos};
+#[cfg(unix)]
+use aoc::budget::{self, Budget, Bytes};
+use aoc::cache::{self, Answers};
+use aoc::client::{Client, Fetched};
+use aoc::submit::{History, Verdict};
+use aoc::watch::Watch;
+use aoc::repl;
+use aoc::report::{self, Status};
+use aoc::{cache_dir, check, day_dir, days, scaffold, state_dir, stats, workspace_root};
+use aoc_core::{parallel, trace, InputArgs, InputSource, Puzzle, Vec2};
+use clap::{Args, Parser, Subcommand, ValueEnum};
+
+#[derive(Parser)]
+#[command(name = "aoc", about = "Runs the Advent of Code solutions")]
+struct Cli {
+    /// Event year to work on [default: the latest year in the workspace]
+    #[arg(long, global = true, env = "AOC_YEAR", value_parser = clap::value_parser!(u16).range(2015..))]
+    year: Option<u16>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Run one day, or every day with --all
+    Run(RunArgs),
+    /// Compare answers with each day's answers.toml
+    Check(CheckArgs),
+    /// Play a day's simulation in the terminal
+    Animate(AnimateArgs),
+    /// Time parsing and each part over repeated runs
+    Bench(BenchArgs),
+    /// Download puzzle inputs that are not on disk yet
+    Fetch(FetchArgs),
+    /// Submit an answer and record the verdict in YYYY/dayNN/submissions.json
+    Submit(SubmitArgs),
+    /// Create and register the crate for a new day
+    New(NewArgs),
+    /// Print a random input for a day
+    Gen(GenArgs),
+    /// Rerun a day whenever its source, input or answers change
+    Watch(WatchArgs),
+    /// Forget cached answers, so the next run solves again
+    Invalidate(InvalidateArgs),
+    /// Summarise each day's progress, timings and size in a table
+    Stats(StatsArgs),
+    /// Load a day's input and explore it with the day's commands
+    Repl(ReplArgs),
+}
+
+#[derive(Args)]
+struct RunArgs {
+    /// Day to run
+    #[arg(
+        required_unless_present = "all",
+        conflicts_with = "all",
+        value_parser = clap::value_parser!(u8).range(1..=25),
+    )]
+    day: Option<u8>,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Run every day of the year
+    #[arg(long, conflicts_with = "input")]
+    all: bool,
+
+    /// How to print the results
+    #[arg(long, value_enum, default_value_t = Format::Text)]
+    format: Format,
+
+    /// Save a picture of the day's input to this SVG file
+    #[arg(long, value_name = "FILE", conflicts_with = "all")]
+    render: Option<PathBuf>,
+
+    /// Solve every part, without reading or storing cached answers
+    #[arg(long)]
+    no_cache: bool,
+
+    /// Run each part in a process of its own, reporting its time and peak
+    /// memory; always solves, without the cache
+    #[arg(long, conflicts_with = "render")]
+    isolate: bool,
+
+    /// Stop a part that takes longer than this many seconds; implies --isolate
+    #[arg(long, value_name = "SECONDS", value_parser = parse_seconds, conflicts_with = "render")]
+    timeout: Option<Duration>,
+
+    /// Stop a part that uses more than this many MiB of memory; implies
+    /// --isolate
+    #[arg(long, value_name = "MIB", value_parser = clap::value_parser!(u64).range(1..), conflicts_with = "render")]
+    memory: Option<u64>,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum Format {
+    /// One line per part
+    Text,
+    /// A JSON array with one record per day and part, including timings
+    Json,
+}
+
+#[derive(Args)]
+struct CheckArgs {
+    /// Day to check; every day when omitted
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct AnimateArgs {
+    /// Day to animate
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Frames per second
+    #[arg(long, default_value_t = 10.0)]
+    fps: f64,
+
+    /// Start paused, to step through frames one at a time
+    #[arg(long)]
+    paused: bool,
+
+    /// How to show frames larger than the terminal
+    #[arg(long, value_enum, default_value_t = Fit::Downsample)]
+    fit: Fit,
+
+    /// Largest frame width in cells [default: terminal width]
+    #[arg(long, value_name = "CELLS")]
+    max_width: Option<usize>,
+
+    /// Largest frame height in cells [default: terminal height]
+    #[arg(long, value_name = "CELLS")]
+    max_height: Option<usize>,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Args)]
+struct BenchArgs {
+    /// Day to benchmark; every day when omitted
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// How many times to run each phase
+    #[arg(short, long, default_value_t = 10)]
+    runs: usize,
+
+    /// Baseline file to compare against [default: bench/baseline.json]
+    #[arg(long, value_name = "PATH")]
+    baseline: Option<PathBuf>,
+
+    /// Store these timings in the baseline file
+    #[arg(long)]
+    save: bool,
+
+    /// Percentage slowdown of the median that counts as a regression
+    #[arg(long, default_value_t = 10.0)]
+    threshold: f64,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Args)]
+struct FetchArgs {
+    /// Day to download; every day when omitted
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+
+    /// Site to download from [default: $AOC_BASE_URL or https://adventofcode.com]
+    #[arg(long, value_name = "URL")]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct SubmitArgs {
+    /// Day to submit
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Part to submit
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: u8,
+
+    /// Answer to submit; computed from the puzzle input when omitted
+    answer: Option<String>,
+
+    /// Site to submit to [default: $AOC_BASE_URL or https://adventofcode.com]
+    #[arg(long, value_name = "URL")]
+    base_url: Option<String>,
+}
+
+#[derive(Args)]
+struct NewArgs {
+    /// Day to create
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+}
+
+#[derive(Args)]
+struct GenArgs {
+    /// Day to generate an input for
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Seed for the generator; the same seed always gives the same input
+    #[arg(long, default_value_t = 0)]
+    seed: u64,
+
+    /// Roughly how many lines or items to generate
+    #[arg(long, default_value_t = 100)]
+    size: usize,
+
+    /// Write the input to this file instead of stdout
+    #[arg(short, long, value_name = "PATH")]
+    output: Option<PathBuf>,
+}
+
+#[derive(Args)]
+struct WatchArgs {
+    /// Day to watch
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    /// Only run this part
+    #[arg(short, long, value_parser = clap::value_parser!(u8).range(1..=2))]
+    part: Option<u8>,
+
+    /// Build with optimizations, for days that are slow without them
+    #[arg(long)]
+    release: bool,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+#[derive(Args)]
+struct InvalidateArgs {
+    /// Day whose answers to forget; every day when omitted
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: Option<u8>,
+}
+
+#[derive(Args)]
+struct StatsArgs {
+    /// How to print the table
+    #[arg(long, value_enum, default_value_t = StatsFormat::Text)]
+    format: StatsFormat,
+
+    /// Also write the markdown table into the README, between its stats
+    /// markers for the year
+    #[arg(long)]
+    update_readme: bool,
+}
+
+#[derive(Clone, Copy, PartialEq, Eq, ValueEnum)]
+enum StatsFormat {
+    /// Aligned columns
+    Text,
+    /// A markdown table
+    Markdown,
+}
+
+#[derive(Args)]
+struct ReplArgs {
+    /// Day to explore
+    #[arg(value_parser = clap::value_parser!(u8).range(1..=25))]
+    day: u8,
+
+    #[command(flatten)]
+    input: InputArgs,
+}
+
+fn parse_seconds(text: &str) -> Result<Duration, String> {
+    let seconds = text.parse::<f64>().map_err(|err| err.to_string())?;
+    Duration::try_from_secs_f64(seconds)
+        .ok()
+        .filter(|duration| !duration.is_zero())
+        .ok_or_else(|| format!("{} is not a positive number of seconds", text))
+}
+
+fn find_day(year: u16, day: u8) -> Result<&'static dyn Puzzle, String> {
+    days::find(year, day).ok_or(format!("day {} of {} is not in the workspace", day, year))
+}
+
+fn select_days(year: u16, day: Option<u8>) -> Result<Vec<&'static dyn Puzzle>, String> {
+    let known = days::year(year).ok_or(format!("{} is not in the workspace", year))?;
+
+    match day {
+        Some(day) => Ok(vec![find_day(year, day)?]),
+        None => Ok(known.days.to_vec()),
+    }
+}
+
+fn run(year: u16, args: &RunArgs) -> Result<(), String> {
+    let puzzles = select_days(year, args.day)?;
+
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    let cache_dir = cache_dir();
+    let answers = Answers { dir: (!args.no_cache).then_some(cache_dir.as_path()) };
+
+    let source = args.input.source();
+    if args.isolate || args.timeout.is_some() || args.memory.is_some() {
+        return run_isolated(year, &puzzles, &source, &parts, args);
+    }
+    if args.format == Format::Json {
+        return run_json(year, &puzzles, &source, &parts, answers);
+    }
+
+    // Days run at once with the `parallel` feature, so each one's output is
+    // held back and printed in day order.
+    let outcomes = parallel::map(&puzzles, |&puzzle| {
+        let _span = trace::span(format!("day {:02}", puzzle.day()));
+        let input = source
+            .load(&day_dir(year, puzzle.day()))
+            .map_err(|err| format!("Day {:02}: {}\n", puzzle.day(), err))?;
+        let lines = answers.solve_parts(year, puzzle, &input, &parts)?;
+        Ok::<_, String>((input, lines))
+    });
+
+    let mut failed = 0;
+
+    for (puzzle, outcome) in puzzles.iter().zip(outcomes) {
+        match outcome {
+            Ok((input, lines)) => {
+                let mut answered = true;
+                for line in lines {
+                    match line {
+                        Ok(line) => println!("{}", line),
+                        Err(line) => {
+                            eprintln!("{}", line);
+                            answered = false;
+                        }
+                    }
+                }
+                if !answered {
+                    failed += 1;
+                }
+
+                if let Some(path) = &args.render {
+                    aoc_core::solution::render_to(*puzzle, &input, path)?;
+                    println!("Picture saved to {}", path.display());
+                }
+            }
+            Err(err) => {
+                eprint!("{}", err);
+                failed += 1;
+            }
+        }
+    }
+
+    trace::report();
+
+    match failed {
+        0 => Ok(()),
+        1 => Err("1 day failed".to_string()),
+        n => Err(format!("{} days failed", n)),
+    }
+}
+
+fn run_json(
+    year: u16,
+    puzzles: &[&dyn Puzzle],
+    source: &InputSource,
+    parts: &[u8],
+    answers: Answers,
+) -> Result<(), String> {
+    let records = parallel::map(puzzles, |&puzzle| {
+        let _span = trace::span(format!("day {:02}", puzzle.day()));
+        report::run_day(year, puzzle, source, &day_dir(year, puzzle.day()), parts, answers)
+    })
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+    trace::report();
+
+    println!("{}", serde_json::to_string_pretty(&records).expect("records serialize"));
+
+    match records.iter().filter(|record| record.status == Status::Error).count() {
+        0 => Ok(()),
+        1 => Err("1 part could not be run".to_string()),
+        n => Err(format!("{} parts could not be run", n)),
+    }
+}
+
+#[cfg(unix)]
+fn run_isolated(
+    year: u16,
+    puzzles: &[&dyn Puzzle],
+    source: &InputSource,
+    parts: &[u8],
+    args: &RunArgs,
+) -> Result<(), String> {
+    let budget = Budget {
+        timeout: args.timeout,
+        memory: args.memory.map(|mib| mib * 1024 * 1024),
+    };
+    let exe = std::env::current_exe().map_err(|err| format!("cannot find the runner itself: {}", err))?;
+    let records = parallel::map(puzzles, |&puzzle| {
+        budget::run_day(&exe, year, puzzle, source, &day_dir(year, puzzle.day()), parts, budget)
+    })
+    .into_iter()
+    .flatten()
+    .collect::<Vec<_>>();
+
+    if args.format == Format::Json {
+        println!("{}", serde_json::to_string_pretty(&records).expect("records serialize"));
+    } else {
+        for record in &records {
+            let label = format!("Day {:02} part {}", record.day, record.part);
+            match (record.status, &record.answer) {
+                (Status::Ok, Some(answer)) => println!(
+                    "{}: {}  ({}, {} peak)",
+                    label,
+                    answer,
+                    Nanos(record.elapsed_ns.unwrap_or_default()),
+                    Bytes(record.peak_rss.unwrap_or_default())
+                ),
+                (Status::NotImplemented, _) => println!("{}: not implemented", label),
+                _ => eprintln!("{}: {}", label, record.error.as_deref().unwrap_or("no answer")),
+            }
+        }
+    }
+
+    match records.iter().filter(|record| record.status == Status::Error).count() {
+        0 => Ok(()),
+        1 => Err("1 part could not be run".to_string()),
+        n => Err(format!("{} parts could not be run", n)),
+    }
+}
+
+#[cfg(not(unix))]
+fn run_isolated(
+    _year: u16,
+    _puzzles: &[&dyn Puzzle],
+    _source: &InputSource,
+    _parts: &[u8],
+    _args: &RunArgs,
+) -> Result<(), String> {
+    Err("running parts in their own processes needs a Unix system".to_string())
+}
+
+fn check(year: u16, args: &CheckArgs) -> Result<(), String> {
+    let mut passed = 0;
+    let mut failed = 0;
+
+    let puzzles = select_days(year, args.day)?;
+    let results = parallel::map(&puzzles, |&puzzle| check::check_day(puzzle, &day_dir(year, puzzle.day())));
+
+    for results in results {
+        for result in results? {
+            println!("{}", result);
+
+            if result.passed() {
+                passed += 1;
+            } else {
+                failed += 1;
+            }
+        }
+    }
+
+    println!("{} passed, {} failed", passed, failed);
+
+    match failed {
+        0 => Ok(()),
+        _ => Err("some answers do not match answers.toml".to_string()),
+    }
+}
+
+fn animate(year: u16, args: &AnimateArgs) -> Result<(), String> {
+    let puzzle = find_day(year, args.day)?;
+    let input = args.input.source().load(&day_dir(year, args.day)).map_err(|err| err.to_string())?;
+    let parsed = puzzle
+        .parse_input(&input.text)
+        .map_err(|err| err.render(&input.origin, &input.text))?;
+    let frames = puzzle
+        .animate(parsed.as_ref())
+        .ok_or_else(|| format!("day {} has no animation", args.day))?;
+
+    // Leave two lines below the frame for the caption and status.
+    let (columns, rows) = crossterm::terminal::size().unwrap_or((80, 24));
+    let max = Vec2::new(
+        args.max_width.unwrap_or(columns as usize),
+        args.max_height.unwrap_or((rows as usize).saturating_sub(2)),
+    );
+
+    let interactive = animate::interactive();
+    let player = Player {
+        fps: args.fps.max(0.25),
+        max,
+        fit: args.fit,
+        paused: args.paused && interactive,
+    };
+
+    let mut out = std::io::stdout();
+    let played = if interactive {
+        let mut keyboard = Keyboard::new().map_err(|err| format!("cannot read keys: {}", err))?;
+        print!("\x1b[?25l");
+        let played = player.play(&frames, &mut out, &mut keyboard);
+        print!("\x1b[?25h");
+        played
+    } else {
+        player.play(&frames, &mut out, &mut NoControls)
+    };
+
+    played.map(|_| ()).map_err(|err| format!("cannot draw: {}", err))
+}
+
+fn bench(year: u16, args: &BenchArgs) -> Result<(), String> {
+    let path = args.baseline.clone().unwrap_or_else(|| workspace_root().join("bench").join("baseline.json"));
+    let mut baseline = Baseline::load(&path)?;
+    let source = args.input.source();
+    let mut regressions = 0;
+    let mut failed = 0;
+
+    for puzzle in select_days(year, args.day)? {
+        // A day that cannot be measured is reported and left out, and the
+        // baseline keeps its earlier timings.
+        let measured = source
+            .load(&day_dir(year, puzzle.day()))
+            .map_err(|err| format!("Day {:02}: {}\n", puzzle.day(), err))
+            .and_then(|input| bench::bench_day(puzzle, &input, args.runs.max(1)));
+        let phases = match measured {
+            Ok(phases) => phases,
+            Err(err) => {
+                eprint!("{}", err);
+                failed += 1;
+                continue;
+            }
+        };
+        let day = format!("{}/day{:02}", year, puzzle.day());
+
+        for (phase, stats) in &phases {
+            print!(
+                "Day {:02} {:<6} median {:>9}  min {:>9}  stddev {:>9}",
+                puzzle.day(),
+                phase,
+                Nanos(stats.median_ns),
+                Nanos(stats.min_ns),
+                Nanos(stats.stddev_ns),
+            );
+
+            if let Some(previous) = baseline.get(&day, phase) {
+                print!("  {:+.1}%", stats.change_from(previous) * 100.0);
+
+                if bench::is_regression(stats, previous, args.threshold / 100.0) {
+                    print!("  REGRESSION (baseline {})", Nanos(previous.median_ns));
+                    regressions += 1;
+                }
+            }
+            println!();
+        }
+
+        if args.save {
+            baseline.days.insert(day, phases);
+        }
+    }
+
+    if args.save {
+        baseline.save(&path)?;
+        println!("Baseline saved to {}", path.display());
+    }
+
+    let mut problems = vec![];
+    if regressions > 0 {
+        problems.push(format!("{} phase(s) regressed by more than {}%", regressions, args.threshold));
+    }
+    match failed {
+        0 => {}
+        1 => problems.push("1 day could not be measured".to_string()),
+        n => problems.push(format!("{} days could not be measured", n)),
+    }
+
+    if problems.is_empty() {
+        Ok(())
+    } else {
+        Err(problems.join("; "))
+    }
+}
+
+fn fetch(year: u16, args: &FetchArgs) -> Result<(), String> {
+    let mut client = Client::from_env(&state_dir(), year);
+    if let Some(base_url) = &args.base_url {
+        client.base_url = base_url.clone();
+    }
+
+    for puzzle in select_days(year, args.day)? {
+        let day = puzzle.day();
+
+        match client.fetch_input(day, &day_dir(year, day)).map_err(|err| format!("day {}: {}", day, err))? {
+            Fetched::Cached(path) => println!("Day {:02}: already downloaded to {}", day, path.display()),
+            Fetched::Downloaded(path) => println!("Day {:02}: downloaded to {}", day, path.display()),
+        }
+    }
+
+    Ok(())
+}
+
+fn submit(year: u16, args: &SubmitArgs) -> Result<(), String> {
+    let dir = day_dir(year, args.day);
+    let answer = match &args.answer {
+        Some(answer) => answer.trim().to_string(),
+        None => {
+            let puzzle = find_day(year, args.day)?;
+            if args.part > puzzle.parts() {
+                return Err(format!("day {} part {} is not implemented", args.day, args.part));
+            }
+
+            let input = InputSource::Puzzle.load(&dir).map_err(|err| err.to_string())?;
+            let parsed = puzzle.parse_input(&input.text).map_err(|err| err.render(&input.origin, &input.text))?;
+            puzzle
+                .solve_part(parsed.as_ref(), args.part)
+                .map_err(|err| format!("day {} part {} has no answer: {}", args.day, args.part, err))?
+        }
+    };
+
+    let mut history = History::load(&dir)?;
+    history
+        .check(args.part, &answer)
+        .map_err(|refusal| format!("not submitting {}: {}", answer, refusal))?;
+
+    let mut client = Client::from_env(&state_dir(), year);
+    if let Some(base_url) = &args.base_url {
+        client.base_url = base_url.clone();
+    }
+
+    let verdict = client.submit(args.day, args.part, &answer).map_err(|err| err.to_string())?;
+    history.record(args.part, &answer, verdict);
+    history.save(&dir)?;
+
+    println!("Day {:02} part {}: {} is {}", args.day, args.part, answer, verdict);
+
+    match verdict {
+        Verdict::Correct | Verdict::AlreadySolved => Ok(()),
+        _ => Err(format!("{} was not accepted", answer)),
+    }
+}
+
+fn new(year: u16, args: &NewArgs) -> Result<(), String> {
+    let dir = scaffold::new_day(&workspace_root(), year, args.day)?;
+
+    println!("Created {}", dir.display());
+    println!(
+        "Registered aoc{}-day{:02} with the workspace, the runner, the answer tests and the fuzz targets",
+        year, args.day
+    );
+    Ok(())
+}
+
+fn generate(year: u16, args: &GenArgs) -> Result<(), String> {
+    let puzzle = find_day(year, args.day)?;
+    let input = puzzle
+        .generate(args.seed, args.size)
+        .ok_or(format!("day {} has no generator", args.day))?;
+
+    match &args.output {
+        Some(path) => std::fs::write(path, input).map_err(|err| format!("cannot write {}: {}", path.display(), err)),
+        None => {
+            print!("{}", input);
+            Ok(())
+        }
+    }
+}
+
+fn watch(year: u16, args: &WatchArgs) -> Result<(), String> {
+    find_day(year, args.day)?;
+    let parts = match args.part {
+        Some(part) => vec![part],
+        None => vec![1, 2],
+    };
+
+    Watch::new(year, args.day, parts, &args.input.source(), args.release)?.watch()
+}
+
+fn invalidate(year: u16, args: &InvalidateArgs) -> Result<(), String> {
+    let cleared = cache::clear(&cache_dir(), year, args.day)?;
+
+    match (args.day, cleared) {
+        (Some(day), 0) => println!("No cached answers for day {:02}", day),
+        (Some(day), _) => println!("Forgot the cached answers for day {:02}", day),
+        (None, 1) => println!("Forgot the cached answers for 1 day"),
+        (None, n) => println!("Forgot the cached answers for {} days", n),
+    }
+    Ok(())
+}
+
+fn stats(year: u16, args: &StatsArgs) -> Result<(), String> {
+    // Days run one after another so their timings do not skew each other.
+    let days = select_days(year, None)?
+        .into_iter()
+        .map(|puzzle| stats::day_stats(year, puzzle, &day_dir(year, puzzle.day())))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    let layout = match args.format {
+        StatsFormat::Text => stats::Layout::Text,
+        StatsFormat::Markdown => stats::Layout::Markdown,
+    };
+    print!("{}", stats::table(&days, layout));
+
+    if args.update_readme {
+        let path = workspace_root().join("README.md");
+        let readme = std::fs::read_to_string(&path).map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+        let updated = stats::update_section(&readme, year, &stats::table(&days, stats::Layout::Markdown))
+            .map_err(|err| format!("{}: {}", path.display(), err))?;
+        std::fs::write(&path, updated).map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+        eprintln!("Updated {}", path.display());
+    }
+    Ok(())
+}
+
+fn repl(year: u16, args: &ReplArgs) -> Result<(), String> {
+    let puzzle = find_day(year, args.day)?;
+    let input = args.input.source().load(&day_dir(year, args.day)).map_err(|err| err.to_string())?;
+
+    repl::session(puzzle, &input, &repl::history_path(&state_dir(), year, args.day))
+}
+
+fn main() -> ExitCode {
+    let cli = Cli::parse();
+    let year = cli.year.unwrap_or_else(days::latest);
+
+    let result = match &cli.command {
+        Command::Run(args) => run(year, args),
+        Command::Check(args) => check(year, args),
+        Command::Animate(args) => animate(year, args),
+        Command::Bench(args) => bench(year, args),
+        Command::Fetch(args) => fetch(year, args),
+        Command::Submit(args) => submit(year, args),
+        Command::New(args) => new(year, args),
+        Command::Gen(args) => generate(year, args),
+        Command::Watch(args) => watch(year, args),
+        Command::Invalidate(args) => invalidate(year, args),
+        Command::Stats(args) => stats(year, args),
+        Command::Repl(args) => repl(year, args),
+    };
+
+    match result {
+        Ok(()) => ExitCode::SUCCESS,
+        Err(err) => {
+            eprintln!("error: {}", err);
+            ExitCode::FAILURE
+        }
+    }
+}
@@ -0,0 +1,172 @@
+use std::any::Any;
+use std::panic::{self, AssertUnwindSafe};
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use aoc_core::{Input, Puzzle, Table, Usage};
+use rustyline::error::ReadlineError;
+use rustyline::DefaultEditor;
+
+use crate::bench::Nanos;
+use crate::check::panic_message;
+
+/// Commands every day has, listed by `help` ahead of the day's own.
+const BUILTINS: &[Usage] = &[
+    Usage { name: "help", args: "", help: "List the commands" },
+    Usage { name: "parts", args: "", help: "Solve each part, with its answer and how long it took" },
+    Usage { name: "quit", args: "", help: "Leave, as does Ctrl-D" },
+];
+
+/// What to do after a line typed at the prompt.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Reply {
+    /// Text to print, usually a table; empty for a blank line.
+    Output(String),
+    /// An error to report before the next prompt.
+    Error(String),
+    Quit,
+}
+
+/// Where the lines typed at `day`'s prompt are kept between sessions.
+pub fn history_path(state_dir: &Path, year: u16, day: u8) -> PathBuf {
+    state_dir.join("repl").join(year.to_string()).join(format!("day{:02}.txt", day))
+}
+
+fn help(puzzle: &dyn Puzzle) -> Table {
+    BUILTINS
+        .iter()
+        .copied()
+        .chain(puzzle.commands())
+        .fold(Table::new(&["Command", "Arguments", "Description"]), |table, usage| {
+            table.row([usage.name, usage.args, usage.help])
+        })
+}
+
+fn parts(puzzle: &dyn Puzzle, parsed: &dyn Any) -> Table {
+    let mut table = Table::new(&["Part", "Answer", "Time"]);
+
+    for part in 1..=2 {
+        if part > puzzle.parts() {
+            table = table.row([part.to_string(), "not implemented".to_string(), "-".to_string()]);
+            continue;
+        }
+
+        let start = Instant::now();
+        let answer = match panic::catch_unwind(AssertUnwindSafe(|| puzzle.solve_part(parsed, part))) {
+            Ok(Ok(answer)) => answer,
+            Ok(Err(err)) => format!("error: {}", err),
+            Err(payload) => format!("panicked: {}", panic_message(payload)),
+        };
+        let time = Nanos(start.elapsed().as_nanos() as u64);
+        table = table.row([part.to_string(), answer, time.to_string()]);
+    }
+    table
+}
+
+/// Answers one line typed at the prompt, about `parsed`, the day's input.
+pub fn respond(puzzle: &dyn Puzzle, parsed: &dyn Any, line: &str) -> Reply {
+    let words = line.split_whitespace().collect::<Vec<_>>();
+    let Some((&name, args)) = words.split_first() else {
+        return Reply::Output(String::new());
+    };
+
+    match name {
+        "help" => Reply::Output(help(puzzle).to_string()),
+        "parts" => Reply::Output(parts(puzzle, parsed).to_string()),
+        "quit" | "exit" => Reply::Quit,
+        _ => match panic::catch_unwind(AssertUnwindSafe(|| puzzle.run_command(parsed, name, args))) {
+            Ok(Some(Ok(table))) => Reply::Output(table.to_string()),
+            Ok(Some(Err(err))) => Reply::Error(err),
+            Ok(None) => Reply::Error(format!("no command `{}`; `help` lists them", name)),
+            Err(payload) => Reply::Error(format!("`{}` panicked: {}", name, panic_message(payload))),
+        },
+    }
+}
+
+/// Parses `input` and reads commands about it until `quit` or end of
+/// input, keeping the lines typed in the file at `history`.
+pub fn session(puzzle: &dyn Puzzle, input: &Input, history: &Path) -> Result<(), String> {
+    let parsed = puzzle
+        .parse_input(&input.text)
+        .map_err(|err| err.render(&input.origin, &input.text))?;
+
+    let mut editor = DefaultEditor::new().map_err(|err| format!("cannot start the line editor: {}", err))?;
+    // A first session has no history yet.
+    let _ = editor.load_history(history);
+
+    println!("Day {:02}: {} is loaded. `help` lists the commands.", puzzle.day(), input.origin);
+    let prompt = format!("day{:02}> ", puzzle.day());
+
+    loop {
+        let line = match editor.readline(&prompt) {
+            Ok(line) => line,
+            // Ctrl-C drops the line being typed, as in a shell.
+            Err(ReadlineError::Interrupted) => continue,
+            Err(ReadlineError::Eof) => break,
+            Err(err) => return Err(format!("cannot read a command: {}", err)),
+        };
+        if !line.trim().is_empty() {
+            let _ = editor.add_history_entry(line.as_str());
+        }
+
+        match respond(puzzle, parsed.as_ref(), &line) {
+            Reply::Output(text) => print!("{}", text),
+            Reply::Error(err) => eprintln!("error: {}", err),
+            Reply::Quit => break,
+        }
+    }
+
+    // Losing the history is only worth a warning; the session went fine.
+    let saved = history
+        .parent()
+        .map_or(Ok(()), std::fs::create_dir_all)
+        .map_err(|err| err.to_string())
+        .and_then(|()| editor.save_history(history).map_err(|err| err.to_string()));
+    if let Err(err) = saved {
+        eprintln!("warning: cannot save {}: {}", history.display(), err);
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::days;
+
+    fn reply(day: u8, input: &str, line: &str) -> Reply {
+        let puzzle = days::find(2025, day).unwrap();
+        let parsed = puzzle.parse_input(input).unwrap();
+        respond(puzzle, parsed.as_ref(), line)
+    }
+
+    #[test]
+    fn runs_a_days_commands() {
+        let db = "3-5\n10-14\n16-20\n12-18\n\n1\n5\n8\n11\n17\n32\n";
+
+        assert_eq!(
+            reply(5, db, "fresh 5 8"),
+            Reply::Output("ID  Fresh  Range\n--  -----  -----\n 5  yes    3-5\n 8  no     -\n".to_string())
+        );
+        assert_eq!(reply(5, db, "fresh x"), Reply::Error("expected an ingredient ID, not `x`".to_string()));
+        assert_eq!(
+            reply(11, "you: aaa out\naaa: out\n", "paths you out"),
+            Reply::Output("From  To   Paths\n----  ---  -----\nyou   out      2\n".to_string())
+        );
+    }
+
+    #[test]
+    fn lists_builtins_and_the_days_commands() {
+        let Reply::Output(help) = reply(3, "987654321111111\n", "help") else { panic!("help failed") };
+
+        for command in ["help", "parts", "quit", "bank", "best"] {
+            assert!(help.lines().any(|line| line.starts_with(command)), "{} missing from\n{}", command, help);
+        }
+    }
+
+    #[test]
+    fn handles_blank_unknown_and_quitting_lines() {
+        assert_eq!(reply(12, "", "  "), Reply::Output(String::new()));
+        assert_eq!(reply(12, "", "fresh 5"), Reply::Error("no command `fresh`; `help` lists them".to_string()));
+        assert_eq!(reply(12, "", "quit"), Reply::Quit);
+    }
+}
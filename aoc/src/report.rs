@@ -0,0 +1,188 @@
+use std::path::Path;
+
+use aoc_core::{InputSource, Puzzle};
+use serde::{Deserialize, Serialize};
+
+use crate::cache::{Answer, Answers, Unparsed};
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Status {
+    Ok,
+    Error,
+    /// The day does not solve this part yet.
+    NotImplemented,
+}
+
+/// The result of running one part of one day, as emitted by
+/// `aoc run --format json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct Record {
+    pub year: u16,
+    pub day: u8,
+    pub part: u8,
+    pub status: Status,
+    pub answer: Option<String>,
+    /// Why there is no answer.
+    pub error: Option<String>,
+    /// The file the input was read from, or `<stdin>`.
+    pub input: String,
+    pub parse_ns: Option<u64>,
+    pub solve_ns: Option<u64>,
+    /// Whether the answer came from the cache rather than the solver, in
+    /// which case nothing was timed.
+    #[serde(default)]
+    pub cached: bool,
+    /// Wall-clock time of the whole process, for parts run in one of their
+    /// own.
+    #[serde(default)]
+    pub elapsed_ns: Option<u64>,
+    /// Peak resident memory in bytes, for parts run in a process of their
+    /// own.
+    #[serde(default)]
+    pub peak_rss: Option<u64>,
+}
+
+impl Record {
+    pub(crate) fn failed(year: u16, day: u8, part: u8, input: &str, error: String) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            status: Status::Error,
+            answer: None,
+            error: Some(error),
+            input: input.to_string(),
+            parse_ns: None,
+            solve_ns: None,
+            cached: false,
+            elapsed_ns: None,
+            peak_rss: None,
+        }
+    }
+
+    fn answered(year: u16, day: u8, part: u8, input: &str, answer: String) -> Self {
+        Self {
+            year,
+            day,
+            part,
+            status: Status::Ok,
+            answer: Some(answer),
+            error: None,
+            input: input.to_string(),
+            parse_ns: None,
+            solve_ns: None,
+            cached: false,
+            elapsed_ns: None,
+            peak_rss: None,
+        }
+    }
+
+    fn cached(year: u16, day: u8, part: u8, input: &str, answer: &str) -> Self {
+        Self {
+            cached: true,
+            ..Self::answered(year, day, part, input, answer.to_string())
+        }
+    }
+}
+
+/// Runs `parts` of `puzzle`, a day of `year`, on the input from `source`,
+/// timing the parse and each part. Every requested part gets a record, with
+/// failures to load, parse or solve reported in it rather than returned.
+/// Panics inside the solution are caught and reported the same way. Parts
+/// with an answer in `answers` are not solved again, and the input is only
+/// parsed when some part has to be; new answers are stored there.
+pub fn run_day(
+    year: u16,
+    puzzle: &dyn Puzzle,
+    source: &InputSource,
+    day_dir: &Path,
+    parts: &[u8],
+    answers: Answers,
+) -> Vec<Record> {
+    let day = puzzle.day();
+    let origin = source
+        .path(day_dir)
+        .map_or("<stdin>".to_string(), |path| path.display().to_string());
+
+    let input = match source.load(day_dir) {
+        Ok(input) => input,
+        Err(err) => return parts.iter().map(|&part| Record::failed(year, day, part, &origin, err.to_string())).collect(),
+    };
+
+    let solved = match answers.solve(year, puzzle, &input.text, parts) {
+        Ok(solved) => solved,
+        Err(Unparsed::Invalid { err, parse_ns }) => {
+            let error = format!("{}:{}:{}: {}", input.origin, err.line, err.column, err);
+            return parts
+                .iter()
+                .map(|&part| Record {
+                    parse_ns: Some(parse_ns),
+                    ..Record::failed(year, day, part, &input.origin, error.clone())
+                })
+                .collect();
+        }
+        Err(Unparsed::Panicked(message)) => {
+            let error = format!("parse panicked: {}", message);
+            return parts.iter().map(|&part| Record::failed(year, day, part, &input.origin, error.clone())).collect();
+        }
+    };
+
+    let parse_ns = solved.parse_ns;
+    parts
+        .iter()
+        .zip(solved.answers)
+        .map(|(&part, answer)| match answer {
+            Answer::Cached(answer) => Record::cached(year, day, part, &input.origin, &answer),
+            Answer::NotImplemented => Record {
+                status: Status::NotImplemented,
+                parse_ns,
+                ..Record::failed(year, day, part, &input.origin, "not implemented".to_string())
+            },
+            Answer::Solved { answer: Ok(answer), solve_ns } => Record {
+                parse_ns,
+                solve_ns: Some(solve_ns),
+                ..Record::answered(year, day, part, &input.origin, answer)
+            },
+            Answer::Solved { answer: Err(err), solve_ns } => Record {
+                parse_ns,
+                solve_ns: Some(solve_ns),
+                ..Record::failed(year, day, part, &input.origin, err)
+            },
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day_dir, days};
+
+    #[test]
+    fn records_answers_and_timings() {
+        let puzzle = days::find(2025, 1).unwrap();
+        let records = run_day(2025, puzzle, &InputSource::Example(1), &day_dir(2025, 1), &[1, 2], Answers { dir: None });
+
+        let answers = records.iter().map(|record| record.answer.as_deref()).collect::<Vec<_>>();
+        assert_eq!(answers, vec![Some("3"), Some("6")]);
+        assert!(records.iter().all(|record| record.status == Status::Ok && record.solve_ns.is_some()));
+        assert!(records[0].input.ends_with("test.txt"));
+    }
+
+    #[test]
+    fn records_missing_inputs_as_errors() {
+        let puzzle = days::find(2025, 1).unwrap();
+        let records = run_day(2025, puzzle, &InputSource::Example(99), &day_dir(2025, 1), &[2], Answers { dir: None });
+
+        assert_eq!(records.len(), 1);
+        assert_eq!(records[0].status, Status::Error);
+        assert_eq!(records[0].answer, None);
+        assert!(records[0].input.ends_with("test99.txt"));
+    }
+
+    #[test]
+    fn serializes_statuses_in_snake_case() {
+        let json = serde_json::to_string(&[Status::Ok, Status::Error, Status::NotImplemented]).unwrap();
+        assert_eq!(json, r#"["ok","error","not_implemented"]"#);
+    }
+}
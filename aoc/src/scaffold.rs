@@ -0,0 +1,222 @@
+use std::path::{Path, PathBuf};
+
+/// Files of a new day crate, relative to its directory, and their templates.
+/// `{{year}}` is replaced by the event year, `{{day}}` by the day number and
+/// `{{dd}}` by the same number padded to two digits.
+const TEMPLATES: &[(&str, &str)] = &[
+    ("Cargo.toml", include_str!("../templates/Cargo.toml.tmpl")),
+    ("src/lib.rs", include_str!("../templates/lib.rs.tmpl")),
+    ("src/main.rs", include_str!("../templates/main.rs.tmpl")),
+    ("answers.toml", include_str!("../templates/answers.toml.tmpl")),
+    ("input.txt", ""),
+    ("test.txt", ""),
+];
+
+/// A day's fuzz target, written to `fuzz/fuzz_targets`.
+const FUZZ_TARGET: &str = include_str!("../templates/fuzz_target.rs.tmpl");
+
+fn fill(template: &str, year: u16, day: u8) -> String {
+    template
+        .replace("{{year}}", &year.to_string())
+        .replace("{{day}}", &day.to_string())
+        .replace("{{dd}}", &format!("{:02}", day))
+}
+
+/// Inserts `line` among the lines of `text` that `is_peer` picks out, keeping
+/// them sorted. Returns `None` when there are no peers to anchor on, and the
+/// text unchanged when `line` is already present.
+fn insert_sorted(text: &str, line: &str, is_peer: impl Fn(&str) -> bool) -> Option<String> {
+    let mut lines = text.lines().collect::<Vec<_>>();
+    if lines.contains(&line) {
+        return Some(text.to_string());
+    }
+
+    let peers = lines.iter().enumerate().filter(|(_, l)| is_peer(l)).map(|(i, _)| i).collect::<Vec<_>>();
+    let last = *peers.last()?;
+    let at = peers.iter().copied().find(|&i| lines[i] > line).unwrap_or(last + 1);
+
+    lines.insert(at, line);
+    Some(lines.join("\n") + "\n")
+}
+
+/// Rewrites the file at `path` by inserting `line` among its peers.
+fn register(path: &Path, line: &str, is_peer: impl Fn(&str) -> bool) -> Result<(), String> {
+    let text = std::fs::read_to_string(path).map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+    let updated = insert_sorted(&text, line, is_peer)
+        .ok_or_else(|| format!("cannot find where to register the day in {}", path.display()))?;
+    std::fs::write(path, updated).map_err(|err| format!("cannot write {}: {}", path.display(), err))
+}
+
+/// Adds `entry` to the runner's slice for `year`, first adding the year to
+/// the table and a slice for it after the others when the year is new.
+fn register_puzzle(text: &str, year: u16, entry: &str) -> Option<String> {
+    let slice = format!("static Y{}: &[&dyn Puzzle] = &[", year);
+    if text.contains(&slice) {
+        let peer = format!("&aoc{}_", year);
+        return insert_sorted(text, entry, |line| line.trim_start().starts_with(&peer));
+    }
+
+    let text = insert_sorted(text, &format!("    Year {{ year: {}, days: Y{} }},", year, year), |line| {
+        line.starts_with("    Year {")
+    })?;
+    Some(format!("{}\n{}\n{}\n];\n", text, slice, entry))
+}
+
+/// Adds the `[[bin]]` section of fuzz target `name` to the end of the fuzz
+/// crate's manifest, unless it is already there.
+fn register_fuzz_target(text: &str, name: &str) -> String {
+    if text.contains(&format!("name = \"{}\"", name)) {
+        return text.to_string();
+    }
+
+    format!(
+        "{}\n[[bin]]\nname = \"{}\"\npath = \"fuzz_targets/{}.rs\"\ntest = false\ndoc = false\nbench = false\n",
+        text, name, name
+    )
+}
+
+/// Creates the `YYYY/dayNN` crate for `day` of `year` inside the workspace at
+/// `root` and registers it with the workspace, the runner, the answer tests
+/// and the fuzz targets. Returns the new crate's directory. An existing
+/// directory is left alone and reported as an error. If any step fails, the
+/// new directory is removed again so the command can be retried; the
+/// registrations already made are skipped the next time.
+pub fn new_day(root: &Path, year: u16, day: u8) -> Result<PathBuf, String> {
+    let dir = root.join(year.to_string()).join(format!("day{:02}", day));
+    if dir.exists() {
+        return Err(format!("{} already exists", dir.display()));
+    }
+
+    if let Err(err) = write_day(&dir, year, day).and_then(|()| register_day(root, year, day)) {
+        let _ = std::fs::remove_dir_all(&dir);
+        return Err(err);
+    }
+    Ok(dir)
+}
+
+/// Writes the day crate's files into `dir`.
+fn write_day(dir: &Path, year: u16, day: u8) -> Result<(), String> {
+    for (file, template) in TEMPLATES {
+        let path = dir.join(file);
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent).map_err(|err| format!("cannot create {}: {}", parent.display(), err))?;
+        }
+        std::fs::write(&path, fill(template, year, day))
+            .map_err(|err| format!("cannot write {}: {}", path.display(), err))?;
+    }
+    Ok(())
+}
+
+/// Registers the day crate with the workspace at `root`, the runner, the
+/// answer tests and the fuzz targets.
+fn register_day(root: &Path, year: u16, day: u8) -> Result<(), String> {
+    let name = format!("day{:02}", day);
+    let package = format!("aoc{}-{}", year, name);
+
+    register(&root.join("Cargo.toml"), &format!("    \"{}/{}\",", year, name), |line| {
+        line.trim_start().starts_with("\"20")
+    })?;
+    register(
+        &root.join("aoc").join("Cargo.toml"),
+        &format!("{} = {{ path = \"../{}/{}\" }}", package, year, name),
+        |line| line.starts_with("aoc20") && line.contains("path"),
+    )?;
+
+    let days = root.join("aoc").join("src").join("days.rs");
+    let text = std::fs::read_to_string(&days).map_err(|err| format!("cannot read {}: {}", days.display(), err))?;
+    let updated = register_puzzle(&text, year, &format!("    &aoc{}_{}::Day{:02},", year, name, day))
+        .ok_or_else(|| format!("cannot find where to register the day in {}", days.display()))?;
+    std::fs::write(&days, updated).map_err(|err| format!("cannot write {}: {}", days.display(), err))?;
+    register(
+        &root.join("aoc").join("tests").join("answers.rs"),
+        &format!("    y{}_{} => ({}, {}),", year, name, year, day),
+        |line| line.trim_start().starts_with('y') && line.contains("=>"),
+    )?;
+
+    let fuzz = root.join("fuzz");
+    let target = format!("y{}_{}", year, name);
+    register(
+        &fuzz.join("Cargo.toml"),
+        &format!("{} = {{ path = \"../{}/{}\" }}", package, year, name),
+        |line| line.starts_with("aoc20") && line.contains("path"),
+    )?;
+    let manifest = fuzz.join("Cargo.toml");
+    let text = std::fs::read_to_string(&manifest).map_err(|err| format!("cannot read {}: {}", manifest.display(), err))?;
+    std::fs::write(&manifest, register_fuzz_target(&text, &target))
+        .map_err(|err| format!("cannot write {}: {}", manifest.display(), err))?;
+    let path = fuzz.join("fuzz_targets").join(format!("{}.rs", target));
+    std::fs::write(&path, fill(FUZZ_TARGET, year, day)).map_err(|err| format!("cannot write {}: {}", path.display(), err))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn is_member(line: &str) -> bool {
+        line.trim_start().starts_with("\"20")
+    }
+
+    #[test]
+    fn inserts_in_order() {
+        let text = "members = [\n    \"aoc\",\n    \"2025/day01\",\n    \"2025/day03\",\n]\n";
+
+        assert_eq!(
+            insert_sorted(text, "    \"2025/day02\",", is_member).unwrap(),
+            "members = [\n    \"aoc\",\n    \"2025/day01\",\n    \"2025/day02\",\n    \"2025/day03\",\n]\n"
+        );
+        assert_eq!(
+            insert_sorted(text, "    \"2026/day01\",", is_member).unwrap(),
+            "members = [\n    \"aoc\",\n    \"2025/day01\",\n    \"2025/day03\",\n    \"2026/day01\",\n]\n"
+        );
+        assert_eq!(insert_sorted(text, "    \"2025/day01\",", is_member).unwrap(), text);
+        assert_eq!(insert_sorted("members = []\n", "    \"2025/day01\",", is_member), None);
+    }
+
+    #[test]
+    fn registers_puzzles_by_year() {
+        let text = "static YEARS: &[Year] = &[\n    Year { year: 2025, days: Y2025 },\n];\n\n\
+                    static Y2025: &[&dyn Puzzle] = &[\n    &aoc2025_day01::Day01,\n];\n";
+
+        assert_eq!(
+            register_puzzle(text, 2025, "    &aoc2025_day02::Day02,").unwrap(),
+            "static YEARS: &[Year] = &[\n    Year { year: 2025, days: Y2025 },\n];\n\n\
+             static Y2025: &[&dyn Puzzle] = &[\n    &aoc2025_day01::Day01,\n    &aoc2025_day02::Day02,\n];\n"
+        );
+        assert_eq!(
+            register_puzzle(text, 2026, "    &aoc2026_day01::Day01,").unwrap(),
+            "static YEARS: &[Year] = &[\n    Year { year: 2025, days: Y2025 },\n    Year { year: 2026, days: Y2026 },\n];\n\n\
+             static Y2025: &[&dyn Puzzle] = &[\n    &aoc2025_day01::Day01,\n];\n\n\
+             static Y2026: &[&dyn Puzzle] = &[\n    &aoc2026_day01::Day01,\n];\n"
+        );
+    }
+
+    #[test]
+    fn adds_each_fuzz_target_once() {
+        let text = "[dependencies]\nlibfuzzer-sys = \"0.4\"\n";
+        let added = register_fuzz_target(text, "y2025_day01");
+
+        assert_eq!(
+            added,
+            "[dependencies]\nlibfuzzer-sys = \"0.4\"\n\n[[bin]]\nname = \"y2025_day01\"\n\
+             path = \"fuzz_targets/y2025_day01.rs\"\ntest = false\ndoc = false\nbench = false\n"
+        );
+        assert_eq!(register_fuzz_target(&added, "y2025_day01"), added);
+    }
+
+    #[test]
+    fn removes_a_day_it_cannot_register() {
+        let root = tempfile::tempdir().unwrap();
+        let dir = root.path().join("2025").join("day01");
+
+        for _ in 0..2 {
+            let err = new_day(root.path(), 2025, 1).unwrap_err();
+            assert!(err.starts_with("cannot read"), "{}", err);
+            assert!(!dir.exists());
+        }
+    }
+
+    #[test]
+    fn fills_in_the_day() {
+        assert_eq!(fill("aoc{{year}}_day{{dd}}: DAY = {{day}}", 2025, 7), "aoc2025_day07: DAY = 7");
+    }
+}
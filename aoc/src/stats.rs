@@ -0,0 +1,240 @@
+use std::path::Path;
+
+use aoc_core::answers::Manifest;
+use aoc_core::{InputSource, Puzzle};
+
+use crate::bench::Nanos;
+use crate::cache::Answers;
+use crate::check;
+use crate::report::{self, Status};
+
+/// The input whose answers count as known, and whose parts are timed.
+pub const PUZZLE_INPUT: &str = "input.txt";
+
+/// How long a part took on the puzzle input.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Timing {
+    NotImplemented,
+    /// The part has no answer, say for a missing input, a panic or an error.
+    Failed,
+    Solved(u64),
+}
+
+impl Timing {
+    fn cell(self) -> String {
+        match self {
+            Timing::NotImplemented => "-".to_string(),
+            Timing::Failed => "error".to_string(),
+            Timing::Solved(ns) => Nanos(ns).to_string(),
+        }
+    }
+}
+
+/// Where one day stands.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DayStats {
+    pub day: u8,
+    /// Parts with a working implementation.
+    pub parts: u8,
+    /// Parts with a known answer for the puzzle input.
+    pub answers: usize,
+    /// Example answers that came out right, out of how many are listed.
+    pub examples: (usize, usize),
+    pub timings: [Timing; 2],
+    /// Non-blank lines of Rust in the day's `src`.
+    pub lines: usize,
+}
+
+/// Gathers a day's stats, solving both parts on the puzzle input and
+/// checking every other input listed in its manifest.
+pub fn day_stats(year: u16, puzzle: &dyn Puzzle, day_dir: &Path) -> Result<DayStats, String> {
+    let manifest = Manifest::load(day_dir)?;
+
+    let answers = manifest
+        .inputs
+        .iter()
+        .find(|listed| listed.file == PUZZLE_INPUT)
+        .map_or(0, |listed| (1..=2).filter(|&part| listed.part(part).is_some()).count());
+
+    let examples = manifest
+        .inputs
+        .into_iter()
+        .filter(|listed| listed.file != PUZZLE_INPUT)
+        .collect::<Vec<_>>();
+    let results = check::check_inputs(puzzle, day_dir, &examples);
+    let passed = results.iter().filter(|result| result.passed()).count();
+
+    let records = report::run_day(year, puzzle, &InputSource::Puzzle, day_dir, &[1, 2], Answers { dir: None });
+    let timings = [0, 1].map(|i| match (records[i].status, records[i].solve_ns) {
+        (Status::NotImplemented, _) => Timing::NotImplemented,
+        (Status::Ok, Some(ns)) => Timing::Solved(ns),
+        _ => Timing::Failed,
+    });
+
+    Ok(DayStats {
+        day: puzzle.day(),
+        parts: puzzle.parts(),
+        answers,
+        examples: (passed, results.len()),
+        timings,
+        lines: count_lines(&day_dir.join("src"))?,
+    })
+}
+
+/// Non-blank lines in the `.rs` files under `dir`.
+fn count_lines(dir: &Path) -> Result<usize, String> {
+    let entries = std::fs::read_dir(dir).map_err(|err| format!("cannot read {}: {}", dir.display(), err))?;
+    let mut lines = 0;
+
+    for entry in entries {
+        let path = entry.map_err(|err| format!("cannot read {}: {}", dir.display(), err))?.path();
+        if path.is_dir() {
+            lines += count_lines(&path)?;
+        } else if path.extension().is_some_and(|ext| ext == "rs") {
+            let text = std::fs::read_to_string(&path).map_err(|err| format!("cannot read {}: {}", path.display(), err))?;
+            lines += text.lines().filter(|line| !line.trim().is_empty()).count();
+        }
+    }
+    Ok(lines)
+}
+
+/// How [`table`] lays out its rows.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Layout {
+    /// Aligned columns for a terminal.
+    Text,
+    /// A GitHub-flavoured markdown table.
+    Markdown,
+}
+
+/// A table with a row per day and a total row.
+pub fn table(days: &[DayStats], layout: Layout) -> String {
+    let header = ["Day", "Parts", "Answers", "Examples", "Part 1", "Part 2", "Lines"].map(str::to_string);
+
+    let mut rows = days
+        .iter()
+        .map(|stats| {
+            [
+                format!("{:02}", stats.day),
+                format!("{}/2", stats.parts),
+                format!("{}/2", stats.answers),
+                match stats.examples {
+                    (_, 0) => "-".to_string(),
+                    (passed, total) => format!("{}/{}", passed, total),
+                },
+                stats.timings[0].cell(),
+                stats.timings[1].cell(),
+                stats.lines.to_string(),
+            ]
+        })
+        .collect::<Vec<_>>();
+
+    // Each part's total covers only the days where it was solved.
+    let solved = |part: usize| {
+        let ns = days
+            .iter()
+            .filter_map(|stats| match stats.timings[part] {
+                Timing::Solved(ns) => Some(ns),
+                _ => None,
+            })
+            .sum::<u64>();
+        Nanos(ns).to_string()
+    };
+    rows.push([
+        "Total".to_string(),
+        format!("{}/{}", days.iter().map(|stats| stats.parts as usize).sum::<usize>(), days.len() * 2),
+        format!("{}/{}", days.iter().map(|stats| stats.answers).sum::<usize>(), days.len() * 2),
+        format!(
+            "{}/{}",
+            days.iter().map(|stats| stats.examples.0).sum::<usize>(),
+            days.iter().map(|stats| stats.examples.1).sum::<usize>()
+        ),
+        solved(0),
+        solved(1),
+        days.iter().map(|stats| stats.lines).sum::<usize>().to_string(),
+    ]);
+
+    let widths = (0..header.len())
+        .map(|column| rows.iter().chain([&header]).map(|row| row[column].chars().count()).max().unwrap_or(0))
+        .collect::<Vec<_>>();
+    let line = |row: &[String; 7]| {
+        let cells = row.iter().zip(&widths).map(|(cell, &width)| format!("{:>width$}", cell));
+        match layout {
+            Layout::Text => cells.collect::<Vec<_>>().join("  ").trim_end().to_string(),
+            Layout::Markdown => format!("| {} |", cells.collect::<Vec<_>>().join(" | ")),
+        }
+    };
+
+    let mut lines = vec![line(&header)];
+    if layout == Layout::Markdown {
+        let rule = widths.iter().map(|&width| format!("{}:", "-".repeat(width + 1))).collect::<Vec<_>>();
+        lines.push(format!("|{}|", rule.join("|")));
+    }
+    lines.extend(rows.iter().map(line));
+    lines.join("\n") + "\n"
+}
+
+fn markers(year: u16) -> (String, String) {
+    (format!("<!-- stats {} -->", year), format!("<!-- /stats {} -->", year))
+}
+
+/// Replaces what lies between `year`'s stats markers in `text` with
+/// `table`, leaving everything else alone.
+pub fn update_section(text: &str, year: u16, table: &str) -> Result<String, String> {
+    let (start, end) = markers(year);
+    let missing = || format!("cannot find the {} and {} markers", start, end);
+
+    let open = text.find(&start).ok_or_else(missing)? + start.len();
+    let close = open + text[open..].find(&end).ok_or_else(missing)?;
+
+    Ok(format!("{}\n{}{}", &text[..open], table, &text[close..]))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{day_dir, days};
+
+    fn stats(day: u8, parts: u8, timings: [Timing; 2]) -> DayStats {
+        DayStats { day, parts, answers: parts as usize, examples: (parts as usize, 2), timings, lines: 40 }
+    }
+
+    #[test]
+    fn lays_out_markdown() {
+        let days = [
+            stats(1, 2, [Timing::Solved(1_500), Timing::Solved(2_000_000)]),
+            stats(2, 0, [Timing::NotImplemented, Timing::NotImplemented]),
+        ];
+
+        assert_eq!(
+            table(&days, Layout::Markdown),
+            "\
+|   Day | Parts | Answers | Examples | Part 1 | Part 2 | Lines |
+|------:|------:|--------:|---------:|-------:|-------:|------:|
+|    01 |   2/2 |     2/2 |      2/2 | 1.50µs | 2.00ms |    40 |
+|    02 |   0/2 |     0/2 |      0/2 |      - |      - |    40 |
+| Total |   2/4 |     2/4 |      2/4 | 1.50µs | 2.00ms |    80 |
+"
+        );
+    }
+
+    #[test]
+    fn replaces_only_the_marked_section() {
+        let readme = "# Title\n\n<!-- stats 2025 -->\nold\n<!-- /stats 2025 -->\n\nMore.\n";
+
+        assert_eq!(
+            update_section(readme, 2025, "new\n").unwrap(),
+            "# Title\n\n<!-- stats 2025 -->\nnew\n<!-- /stats 2025 -->\n\nMore.\n"
+        );
+        assert!(update_section(readme, 2024, "new\n").is_err());
+    }
+
+    #[test]
+    fn counts_a_days_progress() {
+        let stats = day_stats(2025, days::find(2025, 1).unwrap(), &day_dir(2025, 1)).unwrap();
+
+        assert_eq!((stats.parts, stats.answers, stats.examples), (2, 2, (2, 2)));
+        assert!(matches!(stats.timings, [Timing::Solved(_), Timing::Solved(_)]));
+        assert!(stats.lines > 0);
+    }
+}
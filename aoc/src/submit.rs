@@ -0,0 +1,247 @@
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+
+/// File name of the submission history inside each day directory.
+pub const HISTORY_FILE: &str = "submissions.json";
+
+/// What the site said about a submitted answer.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum Verdict {
+    Correct,
+    TooHigh,
+    TooLow,
+    /// Wrong, without a hint in either direction.
+    Wrong,
+    /// Submitted too soon after a previous answer; nothing was judged.
+    Wait { seconds: u64 },
+    /// The part was already solved on the site.
+    AlreadySolved,
+}
+
+impl Verdict {
+    /// Reads the verdict out of the HTML page returned for a submission.
+    pub fn from_response(html: &str) -> Option<Self> {
+        if html.contains("That's the right answer") {
+            Some(Verdict::Correct)
+        } else if html.contains("your answer is too high") {
+            Some(Verdict::TooHigh)
+        } else if html.contains("your answer is too low") {
+            Some(Verdict::TooLow)
+        } else if html.contains("That's not the right answer") {
+            Some(Verdict::Wrong)
+        } else if html.contains("You gave an answer too recently") {
+            Some(Verdict::Wait { seconds: wait_seconds(html) })
+        } else if html.contains("You don't seem to be solving the right level") {
+            Some(Verdict::AlreadySolved)
+        } else {
+            None
+        }
+    }
+
+    /// Whether the verdict rules the answer out.
+    pub fn is_wrong(&self) -> bool {
+        matches!(self, Verdict::TooHigh | Verdict::TooLow | Verdict::Wrong)
+    }
+}
+
+/// Parses "You have 4m 3s left to wait" into seconds.
+fn wait_seconds(html: &str) -> u64 {
+    let Some(start) = html.find("You have ") else { return 60 };
+    let rest = &html[start + "You have ".len()..];
+    let Some(end) = rest.find(" left to wait") else { return 60 };
+
+    rest[..end]
+        .split_whitespace()
+        .map(|part| {
+            let (number, unit) = part.split_at(part.len().saturating_sub(1));
+            let number: u64 = number.parse().unwrap_or(0);
+            match unit {
+                "h" => number * 3600,
+                "m" => number * 60,
+                _ => number,
+            }
+        })
+        .sum()
+}
+
+impl fmt::Display for Verdict {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Verdict::Correct => write!(f, "correct"),
+            Verdict::TooHigh => write!(f, "wrong, too high"),
+            Verdict::TooLow => write!(f, "wrong, too low"),
+            Verdict::Wrong => write!(f, "wrong"),
+            Verdict::Wait { seconds } => write!(f, "submitted too recently, wait {}s", seconds),
+            Verdict::AlreadySolved => write!(f, "already solved"),
+        }
+    }
+}
+
+/// One submitted answer and how it was judged.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Attempt {
+    pub part: u8,
+    pub answer: String,
+    pub verdict: Verdict,
+    /// Unix time of the submission, in seconds.
+    pub at: u64,
+}
+
+/// Why an answer was not sent.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Refusal {
+    AlreadyCorrect(String),
+    KnownWrong(Verdict),
+    AtLeast(String),
+    AtMost(String),
+    Waiting(u64),
+}
+
+impl fmt::Display for Refusal {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Refusal::AlreadyCorrect(answer) => write!(f, "this part was already solved with {}", answer),
+            Refusal::KnownWrong(verdict) => write!(f, "this answer was already submitted: {}", verdict),
+            Refusal::AtLeast(bound) => write!(f, "{} was already too high, so this answer is too", bound),
+            Refusal::AtMost(bound) => write!(f, "{} was already too low, so this answer is too", bound),
+            Refusal::Waiting(seconds) => write!(f, "the site asked to wait, {}s left", seconds),
+        }
+    }
+}
+
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).unwrap_or_default().as_secs()
+}
+
+/// Every answer submitted for a day, stored as JSON in the day directory.
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+pub struct History {
+    pub attempts: Vec<Attempt>,
+}
+
+impl History {
+    pub fn path(day_dir: &Path) -> PathBuf {
+        day_dir.join(HISTORY_FILE)
+    }
+
+    /// Loads the history in `day_dir`; a day without one has no attempts.
+    pub fn load(day_dir: &Path) -> Result<Self, String> {
+        let path = Self::path(day_dir);
+
+        match std::fs::read_to_string(&path) {
+            Ok(text) => serde_json::from_str(&text).map_err(|err| format!("invalid {}: {}", path.display(), err)),
+            Err(err) if err.kind() == std::io::ErrorKind::NotFound => Ok(Self::default()),
+            Err(err) => Err(format!("cannot read {}: {}", path.display(), err)),
+        }
+    }
+
+    pub fn save(&self, day_dir: &Path) -> Result<(), String> {
+        let path = Self::path(day_dir);
+        let json = serde_json::to_string_pretty(self).expect("history serializes");
+        std::fs::write(&path, json + "\n").map_err(|err| format!("cannot write {}: {}", path.display(), err))
+    }
+
+    pub fn record(&mut self, part: u8, answer: &str, verdict: Verdict) {
+        self.attempts.push(Attempt {
+            part,
+            answer: answer.to_string(),
+            verdict,
+            at: now(),
+        });
+    }
+
+    /// Refuses answers the history already rules out: a solved part, a
+    /// repeated wrong answer, a number beyond a known too-high or too-low
+    /// bound, or a submission during a requested wait.
+    pub fn check(&self, part: u8, answer: &str) -> Result<(), Refusal> {
+        self.check_at(part, answer, now())
+    }
+
+    fn check_at(&self, part: u8, answer: &str, now: u64) -> Result<(), Refusal> {
+        let attempts = self.attempts.iter().filter(|attempt| attempt.part == part);
+        let number = answer.parse::<i128>().ok();
+
+        for attempt in attempts {
+            match attempt.verdict {
+                Verdict::Correct => return Err(Refusal::AlreadyCorrect(attempt.answer.clone())),
+                verdict if verdict.is_wrong() && attempt.answer == answer => return Err(Refusal::KnownWrong(verdict)),
+                Verdict::Wait { seconds } if attempt.at + seconds > now => {
+                    return Err(Refusal::Waiting(attempt.at + seconds - now))
+                }
+                _ => {}
+            }
+
+            let (Some(number), Ok(bound)) = (number, attempt.answer.parse::<i128>()) else { continue };
+            match attempt.verdict {
+                Verdict::TooHigh if number >= bound => return Err(Refusal::AtLeast(attempt.answer.clone())),
+                Verdict::TooLow if number <= bound => return Err(Refusal::AtMost(attempt.answer.clone())),
+                _ => {}
+            }
+        }
+
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn attempt(part: u8, answer: &str, verdict: Verdict, at: u64) -> Attempt {
+        Attempt { part, answer: answer.to_string(), verdict, at }
+    }
+
+    #[test]
+    fn reads_verdicts_from_pages() {
+        let page = |text: &str| format!("<html><main><article><p>{}</p></article></main></html>", text);
+
+        assert_eq!(Verdict::from_response(&page("That's the right answer!")), Some(Verdict::Correct));
+        assert_eq!(
+            Verdict::from_response(&page("That's not the right answer; your answer is too high.")),
+            Some(Verdict::TooHigh)
+        );
+        assert_eq!(
+            Verdict::from_response(&page("That's not the right answer; your answer is too low.")),
+            Some(Verdict::TooLow)
+        );
+        assert_eq!(Verdict::from_response(&page("That's not the right answer.")), Some(Verdict::Wrong));
+        assert_eq!(
+            Verdict::from_response(&page("You gave an answer too recently. You have 1m 5s left to wait.")),
+            Some(Verdict::Wait { seconds: 65 })
+        );
+        assert_eq!(Verdict::from_response(&page("Something else")), None);
+    }
+
+    #[test]
+    fn refuses_answers_ruled_out_by_history() {
+        let history = History {
+            attempts: vec![
+                attempt(1, "500", Verdict::TooHigh, 0),
+                attempt(1, "100", Verdict::TooLow, 0),
+                attempt(1, "abc", Verdict::Wrong, 0),
+                attempt(2, "42", Verdict::Correct, 0),
+            ],
+        };
+
+        assert_eq!(history.check_at(1, "500", 1000), Err(Refusal::KnownWrong(Verdict::TooHigh)));
+        assert_eq!(history.check_at(1, "600", 1000), Err(Refusal::AtLeast("500".to_string())));
+        assert_eq!(history.check_at(1, "50", 1000), Err(Refusal::AtMost("100".to_string())));
+        assert_eq!(history.check_at(1, "abc", 1000), Err(Refusal::KnownWrong(Verdict::Wrong)));
+        assert_eq!(history.check_at(1, "250", 1000), Ok(()));
+        assert_eq!(history.check_at(2, "43", 1000), Err(Refusal::AlreadyCorrect("42".to_string())));
+    }
+
+    #[test]
+    fn waits_out_rate_limits() {
+        let history = History {
+            attempts: vec![attempt(1, "7", Verdict::Wait { seconds: 60 }, 1000)],
+        };
+
+        assert_eq!(history.check_at(1, "7", 1030), Err(Refusal::Waiting(30)));
+        assert_eq!(history.check_at(1, "7", 1060), Ok(()));
+    }
+}
@@ -0,0 +1,313 @@
+use std::collections::BTreeMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+use std::process::{Command, Stdio};
+use std::sync::mpsc;
+use std::time::Duration;
+
+use aoc_core::answers::{Manifest, MANIFEST_FILE};
+use aoc_core::InputSource;
+use notify::{EventKind, RecursiveMode, Watcher};
+
+use crate::report::{Record, Status};
+use crate::{day_dir, workspace_root};
+
+/// How long the files must stay quiet before a rerun, so that an editor
+/// saving several files, or a file in several writes, triggers one run.
+const SETTLE: Duration = Duration::from_millis(200);
+
+/// How an answer compares with the last answer its part gave.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Change {
+    /// There was no answer to compare with.
+    First,
+    Same,
+    Changed { previous: String },
+}
+
+/// How an answer compares with the day's answers manifest.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum Expectation {
+    /// The manifest lists no answer for this input and part.
+    Unknown,
+    Matches,
+    Differs { expected: String },
+}
+
+/// One part of a run, compared with the last run and the manifest.
+#[derive(Debug, Clone)]
+pub struct Comparison {
+    pub record: Record,
+    pub change: Change,
+    pub expectation: Expectation,
+}
+
+impl fmt::Display for Comparison {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "Day {:02} part {}: ", self.record.day, self.record.part)?;
+
+        let answer = match (&self.record.status, &self.record.answer) {
+            (Status::Ok, Some(answer)) => answer,
+            (Status::NotImplemented, _) => return write!(f, "not implemented"),
+            _ => return write!(f, "error: {}", self.record.error.as_deref().unwrap_or("no answer")),
+        };
+
+        let change = match &self.change {
+            Change::First => "new".to_string(),
+            Change::Same => "unchanged".to_string(),
+            Change::Changed { previous } => format!("was {}", previous),
+        };
+        let expectation = match &self.expectation {
+            Expectation::Unknown => format!("not in {}", MANIFEST_FILE),
+            Expectation::Matches => format!("matches {}", MANIFEST_FILE),
+            Expectation::Differs { expected } => format!("{} expects {}", MANIFEST_FILE, expected),
+        };
+
+        write!(f, "{}  ({}, {})", answer, change, expectation)
+    }
+}
+
+/// Compares each record of a run with the last answer to the same part in
+/// `previous` and with the answers `manifest` lists for `file`, the input's
+/// path relative to the day directory.
+pub fn compare(
+    records: &[Record],
+    previous: &BTreeMap<u8, String>,
+    manifest: &Manifest,
+    file: Option<&str>,
+) -> Vec<Comparison> {
+    records
+        .iter()
+        .map(|record| {
+            let before = previous.get(&record.part);
+            let change = match (before, &record.answer) {
+                (Some(before), Some(answer)) if before == answer => Change::Same,
+                (Some(before), Some(_)) => Change::Changed { previous: before.clone() },
+                _ => Change::First,
+            };
+
+            let expected = file.and_then(|file| manifest.expected(file, record.part));
+            let expectation = match (expected, &record.answer) {
+                (None, _) => Expectation::Unknown,
+                (Some(expected), Some(answer)) if expected == answer => Expectation::Matches,
+                (Some(expected), _) => Expectation::Differs { expected: expected.to_string() },
+            };
+
+            Comparison { record: record.clone(), change, expectation }
+        })
+        .collect()
+}
+
+/// What `aoc watch` watches and runs.
+#[derive(Debug, Clone)]
+pub struct Watch {
+    pub year: u16,
+    pub day: u8,
+    pub parts: Vec<u8>,
+    /// The input file, as an absolute path.
+    pub input: PathBuf,
+    pub release: bool,
+}
+
+impl Watch {
+    /// Watches `day` of `year` running `parts` on `source`, which must be a
+    /// file.
+    pub fn new(year: u16, day: u8, parts: Vec<u8>, source: &InputSource, release: bool) -> Result<Self, String> {
+        let input = source.path(&day_dir(year, day)).ok_or("cannot watch stdin; pass a file with --input")?;
+        let input = std::path::absolute(&input).map_err(|err| format!("cannot resolve {}: {}", input.display(), err))?;
+
+        Ok(Self { year, day, parts, input, release })
+    }
+
+    /// Whether a change to `path` calls for a rerun: Rust source under the
+    /// day's `src`, the input itself, or the answers manifest.
+    pub fn is_relevant(&self, path: &Path) -> bool {
+        let day_dir = day_dir(self.year, self.day);
+
+        (path.starts_with(day_dir.join("src")) && path.extension().is_some_and(|ext| ext == "rs"))
+            || path == self.input
+            || path == day_dir.join(MANIFEST_FILE)
+    }
+
+    /// Rebuilds the runner, since the day's code is compiled into it, and
+    /// runs the day once with JSON output. The cache is skipped, so every
+    /// rerun is timed actually solving.
+    fn command(&self) -> Command {
+        let mut command = Command::new(std::env::var_os("CARGO").unwrap_or_else(|| "cargo".into()));
+        command.current_dir(workspace_root()).args(["run", "--quiet", "-p", "aoc"]);
+        if self.release {
+            command.arg("--release");
+        }
+        if cfg!(feature = "parallel") {
+            command.args(["--features", "parallel"]);
+        }
+        if cfg!(feature = "trace") {
+            command.args(["--features", "trace"]);
+        }
+
+        command
+            .args(["--", "--year", &self.year.to_string(), "run", &self.day.to_string()])
+            .args(["--format", "json", "--no-cache", "--input"])
+            .arg(&self.input);
+        if let [part] = self.parts[..] {
+            command.args(["--part", &part.to_string()]);
+        }
+        command
+    }
+
+    /// Runs the day once. Compiler errors and anything the day prints to
+    /// stderr go straight to the terminal; a build that fails is an error.
+    fn run(&self) -> Result<Vec<Record>, String> {
+        let output = self
+            .command()
+            .stderr(Stdio::inherit())
+            .output()
+            .map_err(|err| format!("cannot run cargo: {}", err))?;
+
+        // The run fails when a part does, but still prints its records.
+        serde_json::from_slice(&output.stdout).map_err(|_| "build failed".to_string())
+    }
+
+    /// Runs the day, then again after every relevant change, until killed.
+    pub fn watch(&self) -> Result<(), String> {
+        let (sender, events) = mpsc::channel();
+        let mut watcher = notify::recommended_watcher(sender).map_err(|err| format!("cannot watch files: {}", err))?;
+
+        let day_dir = day_dir(self.year, self.day);
+        let input_dir = self.input.parent().unwrap_or(&day_dir);
+        // Directories rather than files, so editors that save by replacing
+        // a file are still noticed.
+        for (dir, mode) in [
+            (day_dir.join("src"), RecursiveMode::Recursive),
+            (day_dir.clone(), RecursiveMode::NonRecursive),
+            (input_dir.to_path_buf(), RecursiveMode::NonRecursive),
+        ] {
+            watcher
+                .watch(&dir, mode)
+                .map_err(|err| format!("cannot watch {}: {}", dir.display(), err))?;
+        }
+
+        let file = self.input.strip_prefix(&day_dir).ok().map(|file| file.to_string_lossy().into_owned());
+        // Answers survive failed runs, so fixing a typo does not lose them.
+        let mut previous = BTreeMap::new();
+
+        loop {
+            println!("Running {} day {:02} on {}", self.year, self.day, self.input.display());
+            match self.run() {
+                Ok(records) => {
+                    let manifest = Manifest::load(&day_dir).unwrap_or_else(|err| {
+                        eprintln!("warning: {}", err);
+                        Manifest::default()
+                    });
+                    for comparison in compare(&records, &previous, &manifest, file.as_deref()) {
+                        println!("{}", comparison);
+                    }
+                    for record in records {
+                        if let Some(answer) = record.answer {
+                            previous.insert(record.part, answer);
+                        }
+                    }
+                }
+                Err(err) => println!("{}", err),
+            }
+
+            println!("Waiting for changes to {} or the input", day_dir.join("src").display());
+            let changed = self.next_change(&events)?;
+            println!("\n{} changed", changed.strip_prefix(workspace_root()).unwrap_or(&changed).display());
+        }
+    }
+
+    /// Waits for a relevant change, then for the files to settle, and
+    /// returns the first path that changed.
+    fn next_change(&self, events: &mpsc::Receiver<notify::Result<notify::Event>>) -> Result<PathBuf, String> {
+        let changed = loop {
+            let event = events
+                .recv()
+                .map_err(|_| "file watcher stopped".to_string())?
+                .map_err(|err| format!("file watcher failed: {}", err))?;
+
+            if matches!(event.kind, EventKind::Access(_)) {
+                continue;
+            }
+            if let Some(path) = event.paths.into_iter().find(|path| self.is_relevant(path)) {
+                break path;
+            }
+        };
+
+        while events.recv_timeout(SETTLE).is_ok() {}
+        Ok(changed)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use aoc_core::answers::ExpectedAnswers;
+
+    fn record(part: u8, answer: &str) -> Record {
+        Record {
+            year: 2025,
+            day: 7,
+            part,
+            status: Status::Ok,
+            answer: Some(answer.to_string()),
+            error: None,
+            input: "day07/test.txt".to_string(),
+            parse_ns: Some(1),
+            solve_ns: Some(1),
+            cached: false,
+            elapsed_ns: None,
+            peak_rss: None,
+        }
+    }
+
+    fn manifest() -> Manifest {
+        Manifest {
+            inputs: vec![ExpectedAnswers {
+                file: "test.txt".to_string(),
+                part1: Some("21".to_string()),
+                part2: Some("40".to_string()),
+            }],
+        }
+    }
+
+    #[test]
+    fn compares_with_last_run_and_manifest() {
+        let previous = BTreeMap::from([(1, "21".to_string()), (2, "39".to_string())]);
+        let records = vec![record(1, "21"), record(2, "41")];
+
+        let lines = compare(&records, &previous, &manifest(), Some("test.txt"))
+            .iter()
+            .map(Comparison::to_string)
+            .collect::<Vec<_>>();
+
+        assert_eq!(
+            lines,
+            vec![
+                "Day 07 part 1: 21  (unchanged, matches answers.toml)",
+                "Day 07 part 2: 41  (was 39, answers.toml expects 40)",
+            ]
+        );
+    }
+
+    #[test]
+    fn first_runs_and_unlisted_inputs() {
+        let comparisons = compare(&[record(1, "5")], &BTreeMap::new(), &manifest(), Some("test2.txt"));
+
+        assert_eq!(comparisons[0].change, Change::First);
+        assert_eq!(comparisons[0].expectation, Expectation::Unknown);
+    }
+
+    #[test]
+    fn reruns_for_source_input_and_manifest_only() {
+        let watch = Watch::new(2025, 7, vec![1, 2], &InputSource::Example(1), false).unwrap();
+        let dir = day_dir(2025, 7);
+
+        assert!(watch.is_relevant(&dir.join("src/lib.rs")));
+        assert!(watch.is_relevant(&dir.join("test.txt")));
+        assert!(watch.is_relevant(&dir.join("answers.toml")));
+        assert!(!watch.is_relevant(&dir.join("input.txt")));
+        assert!(!watch.is_relevant(&dir.join("src/.lib.rs.swp")));
+        assert!(!watch.is_relevant(&dir.join("submissions.json")));
+    }
+}
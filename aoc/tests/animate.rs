@@ -0,0 +1,22 @@
+//! Animations must end on the same state the answers describe.
+
+use aoc_core::InputSource;
+
+fn last_caption(day: u8) -> String {
+    let puzzle = aoc::days::find(2025, day).expect("day is registered");
+    let input = InputSource::Example(1).load(&aoc::day_dir(2025, day)).unwrap();
+    let parsed = puzzle.parse_input(&input.text).unwrap();
+    let frames = puzzle.animate(parsed.as_ref()).expect("day has an animation");
+
+    frames.last().unwrap().caption.clone()
+}
+
+#[test]
+fn day04_takes_the_part2_count() {
+    assert!(last_caption(4).ends_with("43 rolls taken"), "{}", last_caption(4));
+}
+
+#[test]
+fn day07_counts_the_part1_splits() {
+    assert!(last_caption(7).ends_with(": 21 splits"), "{}", last_caption(7));
+}
@@ -0,0 +1,40 @@
+//! Regression tests: every day must reproduce the answers in its manifest.
+
+fn check(year: u16, day: u8) {
+    let puzzle = aoc::days::find(year, day).expect("day is registered");
+    let results = aoc::check::check_day(puzzle, &aoc::day_dir(year, day)).unwrap();
+
+    let failures = results
+        .iter()
+        .filter(|result| !result.passed())
+        .map(|result| result.to_string())
+        .collect::<Vec<_>>();
+
+    assert!(failures.is_empty(), "\n{}", failures.join("\n"));
+}
+
+macro_rules! answer_tests {
+    ($($name:ident => ($year:expr, $day:expr)),* $(,)?) => {
+        $(
+            #[test]
+            fn $name() {
+                check($year, $day);
+            }
+        )*
+    };
+}
+
+answer_tests! {
+    y2025_day01 => (2025, 1),
+    y2025_day02 => (2025, 2),
+    y2025_day03 => (2025, 3),
+    y2025_day04 => (2025, 4),
+    y2025_day05 => (2025, 5),
+    y2025_day06 => (2025, 6),
+    y2025_day07 => (2025, 7),
+    y2025_day08 => (2025, 8),
+    y2025_day09 => (2025, 9),
+    y2025_day10 => (2025, 10),
+    y2025_day11 => (2025, 11),
+    y2025_day12 => (2025, 12),
+}
@@ -0,0 +1,52 @@
+//! Parts run in child processes report their cost, and are stopped when
+//! they go over budget without failing anything else.
+#![cfg(unix)]
+
+use std::path::Path;
+use std::time::Duration;
+
+use aoc::budget::{self, Budget};
+use aoc::report::Status;
+use aoc::{day_dir, days};
+use aoc_core::InputSource;
+
+fn exe() -> &'static Path {
+    Path::new(env!("CARGO_BIN_EXE_aoc"))
+}
+
+#[test]
+fn reports_time_and_peak_memory() {
+    let puzzle = days::find(2025, 1).unwrap();
+    let records = budget::run_day(
+        exe(),
+        2025,
+        puzzle,
+        &InputSource::Example(1),
+        &day_dir(2025, 1),
+        &[1, 2],
+        Budget::default(),
+    );
+
+    let answers = records.iter().map(|record| record.answer.as_deref()).collect::<Vec<_>>();
+    assert_eq!(answers, vec![Some("3"), Some("6")]);
+    assert!(records[0].input.ends_with("test.txt"));
+    assert!(records.iter().all(|record| record.peak_rss.is_some_and(|bytes| bytes > 0)));
+    assert!(records.iter().all(|record| record.elapsed_ns.is_some() && record.solve_ns.is_some()));
+}
+
+#[test]
+fn stops_parts_over_budget() {
+    let puzzle = days::find(2025, 8).unwrap();
+    let run = |budget| {
+        budget::run_day(exe(), 2025, puzzle, &InputSource::Puzzle, &day_dir(2025, 8), &[1], budget).remove(0)
+    };
+
+    let slow = run(Budget { timeout: Some(Duration::from_millis(50)), memory: None });
+    assert_eq!(slow.status, Status::Error);
+    assert_eq!(slow.error.as_deref(), Some("timed out after 50.00ms"));
+
+    let hungry = run(Budget { timeout: None, memory: Some(40 * 1024 * 1024) });
+    assert_eq!(hungry.status, Status::Error);
+    assert_eq!(hungry.error.as_deref(), Some("ran out of memory under the 40.0MiB limit"));
+    assert!(hungry.peak_rss.is_some());
+}
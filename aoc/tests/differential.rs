@@ -0,0 +1,152 @@
+//! The days with clever solutions must agree with the naive ones in
+//! `reference` on small random inputs. proptest shrinks any disagreement to
+//! a minimal input before reporting it.
+
+mod reference;
+
+use proptest::prelude::*;
+
+fn solve(day: u8, input: &str, part: u8) -> String {
+    let puzzle = aoc::days::find(2025, day).expect("day is registered");
+    let parsed = puzzle
+        .parse_input(input)
+        .unwrap_or_else(|err| panic!("{}\n{}", err, input));
+    puzzle
+        .solve_part(parsed.as_ref(), part)
+        .unwrap_or_else(|err| panic!("part {}: {}\n{}", part, err, input))
+}
+
+fn config() -> ProptestConfig {
+    ProptestConfig::with_cases(200)
+}
+
+/// Ranges of IDs of up to seven digits, each at most a few thousand long.
+fn id_ranges() -> impl Strategy<Value = String> {
+    let range = (1u64..10_000_000, 0u64..3_000).prop_map(|(start, len)| format!("{}-{}", start, start + len));
+    prop::collection::vec(range, 1..4).prop_map(|ranges| ranges.join(","))
+}
+
+/// Banks of 12 to 16 batteries rated 1 to 9.
+fn banks() -> impl Strategy<Value = String> {
+    let bank = prop::collection::vec(1u8..=9, 12..=16)
+        .prop_map(|digits| digits.iter().map(|digit| digit.to_string()).collect::<String>());
+    prop::collection::vec(bank, 1..4).prop_map(|banks| banks.join("\n"))
+}
+
+/// Overlapping, nested and touching fresh ranges below 200, then IDs.
+fn database() -> impl Strategy<Value = String> {
+    let range = (0u64..200, 0u64..40).prop_map(|(start, len)| format!("{}-{}", start, start + len));
+    (prop::collection::vec(range, 1..8), prop::collection::vec(0u64..250, 0..10)).prop_map(|(ranges, ids)| {
+        let ids = ids.iter().map(u64::to_string).collect::<Vec<_>>();
+        format!("{}\n\n{}", ranges.join("\n"), ids.join("\n"))
+    })
+}
+
+/// A column of the polygon: its width and where its top and bottom edges sit.
+#[derive(Debug, Clone, Copy)]
+struct Column {
+    width: i64,
+    top: i64,
+    bottom: i64,
+}
+
+/// The outline of columns standing side by side: along the tops, then back
+/// along the bottoms.
+fn outline(columns: &[Column]) -> Vec<(i64, i64)> {
+    let mut xs = vec![1];
+    for column in columns {
+        xs.push(xs[xs.len() - 1] + column.width);
+    }
+
+    let mut points = vec![];
+    for (i, column) in columns.iter().enumerate() {
+        points.push((xs[i], column.top));
+        points.push((xs[i + 1], column.top));
+    }
+    for (i, column) in columns.iter().enumerate().rev() {
+        points.push((xs[i + 1], column.bottom));
+        points.push((xs[i], column.bottom));
+    }
+    points
+}
+
+/// Rectilinear polygons with coordinates below 20, in either direction,
+/// starting at any corner and possibly turned on their side.
+fn polygons() -> impl Strategy<Value = String> {
+    let column = (1i64..=3, 7i64..=12, 0i64..=6).prop_map(|(width, top, bottom)| Column { width, top, bottom });
+    let columns = prop::collection::vec(column, 1..6).prop_filter("neighbouring columns must differ", |columns| {
+        columns.windows(2).all(|pair| pair[0].top != pair[1].top && pair[0].bottom != pair[1].bottom)
+    });
+
+    (columns, any::<bool>(), any::<bool>(), any::<prop::sample::Index>()).prop_map(
+        |(columns, reverse, transpose, start)| {
+            let mut points = outline(&columns);
+            if reverse {
+                points.reverse();
+            }
+            if transpose {
+                points.iter_mut().for_each(|(x, y)| std::mem::swap(x, y));
+            }
+            let start = start.index(points.len());
+            points.rotate_left(start);
+
+            points.iter().map(|(x, y)| format!("{},{}", x, y)).collect::<Vec<_>>().join("\n")
+        },
+    )
+}
+
+/// Machines of 2 to 4 counters and 1 to 4 buttons, with the lights and
+/// joltages from pressing each button up to 6 times.
+fn machines() -> impl Strategy<Value = String> {
+    let machine = (2usize..=4).prop_flat_map(|counters| {
+        let button = prop::collection::btree_set(0..counters, 1..=counters);
+        prop::collection::vec((button, 0u64..=6), 1..=4).prop_map(move |buttons| {
+            let mut joltages = vec![0; counters];
+            for (button, presses) in &buttons {
+                button.iter().for_each(|&counter| joltages[counter] += presses);
+            }
+
+            let lights = joltages.iter().map(|joltage| if joltage % 2 == 1 { '#' } else { '.' }).collect::<String>();
+            let buttons = buttons
+                .iter()
+                .map(|(button, _)| format!("({})", button.iter().map(usize::to_string).collect::<Vec<_>>().join(",")))
+                .collect::<Vec<_>>();
+            let joltages = joltages.iter().map(u64::to_string).collect::<Vec<_>>();
+            format!("[{}] {} {{{}}}", lights, buttons.join(" "), joltages.join(","))
+        })
+    });
+    prop::collection::vec(machine, 1..4).prop_map(|machines| machines.join("\n"))
+}
+
+proptest! {
+    #![proptest_config(config())]
+
+    #[test]
+    fn day02_matches_scanning_every_id(input in id_ranges()) {
+        prop_assert_eq!(solve(2, &input, 1), reference::day02::part1(&input).to_string());
+        prop_assert_eq!(solve(2, &input, 2), reference::day02::part2(&input).to_string());
+    }
+
+    #[test]
+    fn day03_matches_trying_every_choice(input in banks()) {
+        prop_assert_eq!(solve(3, &input, 1), reference::day03::part1(&input).to_string());
+        prop_assert_eq!(solve(3, &input, 2), reference::day03::part2(&input).to_string());
+    }
+
+    #[test]
+    fn day05_matches_listing_every_id(input in database()) {
+        prop_assert_eq!(solve(5, &input, 1), reference::day05::part1(&input).to_string());
+        prop_assert_eq!(solve(5, &input, 2), reference::day05::part2(&input).to_string());
+    }
+
+    #[test]
+    fn day09_matches_coloring_every_tile(input in polygons()) {
+        prop_assert_eq!(solve(9, &input, 1), reference::day09::part1(&input).to_string());
+        prop_assert_eq!(solve(9, &input, 2), reference::day09::part2(&input).to_string());
+    }
+
+    #[test]
+    fn day10_matches_pressing_one_button_at_a_time(input in machines()) {
+        prop_assert_eq!(solve(10, &input, 2), reference::day10::part2(&input).to_string());
+    }
+}
@@ -0,0 +1,87 @@
+mod common;
+
+use std::path::Path;
+use std::time::{Duration, Instant};
+
+use aoc::client::{Client, ClientError, Fetched, Throttle};
+use common::MockServer;
+
+fn client(server: &MockServer, state: &Path, min_interval: Duration) -> Client {
+    Client {
+        base_url: server.url.clone(),
+        session: Some("secret".to_string()),
+        year: 2025,
+        throttle: Throttle {
+            min_interval,
+            stamp: state.join("last-request"),
+        },
+    }
+}
+
+#[test]
+fn downloads_once_then_uses_the_cache() {
+    let server = MockServer::start(|_| (200, "R10\nL5\n".to_string()));
+    let dir = tempfile::tempdir().unwrap();
+    let client = client(&server, dir.path(), Duration::ZERO);
+
+    let path = dir.path().join("input.txt");
+    assert_eq!(client.fetch_input(1, dir.path()).unwrap(), Fetched::Downloaded(path.clone()));
+    assert_eq!(client.fetch_input(1, dir.path()).unwrap(), Fetched::Cached(path.clone()));
+    assert_eq!(std::fs::read_to_string(&path).unwrap(), "R10\nL5\n");
+
+    let requests = server.requests();
+    assert_eq!(requests.len(), 1);
+    assert_eq!(requests[0].method, "GET");
+    assert_eq!(requests[0].path, "/2025/day/1/input");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+}
+
+#[test]
+fn empty_input_files_are_downloaded_again() {
+    let server = MockServer::start(|_| (200, "1-2\n".to_string()));
+    let dir = tempfile::tempdir().unwrap();
+    std::fs::write(dir.path().join("input.txt"), "").unwrap();
+
+    let fetched = client(&server, dir.path(), Duration::ZERO).fetch_input(2, dir.path()).unwrap();
+
+    assert!(matches!(fetched, Fetched::Downloaded(_)));
+    assert_eq!(server.requests().len(), 1);
+}
+
+#[test]
+fn missing_session_fails_without_a_request() {
+    let server = MockServer::start(|_| (200, String::new()));
+    let dir = tempfile::tempdir().unwrap();
+    let mut client = client(&server, dir.path(), Duration::ZERO);
+    client.session = None;
+
+    assert!(matches!(client.fetch_input(3, dir.path()), Err(ClientError::NoSession)));
+    assert!(server.requests().is_empty());
+}
+
+#[test]
+fn locked_puzzles_are_reported_and_not_cached() {
+    let server = MockServer::start(|_| (404, "Not Found".to_string()));
+    let dir = tempfile::tempdir().unwrap();
+
+    let err = client(&server, dir.path(), Duration::ZERO).fetch_input(25, dir.path()).unwrap_err();
+
+    assert!(err.to_string().contains("is the puzzle unlocked yet?"), "{}", err);
+    assert!(!dir.path().join("input.txt").exists());
+}
+
+#[test]
+fn requests_are_throttled() {
+    let server = MockServer::start(|_| (200, "data\n".to_string()));
+    let state = tempfile::tempdir().unwrap();
+    let day1 = tempfile::tempdir().unwrap();
+    let day2 = tempfile::tempdir().unwrap();
+    let client = client(&server, state.path(), Duration::from_millis(300));
+
+    let start = Instant::now();
+    client.fetch_input(1, day1.path()).unwrap();
+    client.fetch_input(2, day2.path()).unwrap();
+
+    assert!(start.elapsed() >= Duration::from_millis(300));
+    assert_eq!(server.requests().len(), 2);
+}
@@ -0,0 +1,39 @@
+//! Generated inputs must parse and solve, and depend only on their seed.
+
+use aoc_core::Puzzle;
+
+fn generators() -> impl Iterator<Item = &'static dyn Puzzle> {
+    aoc::days::year(2025).unwrap().days.iter().copied().filter(|puzzle| puzzle.generate(0, 1).is_some())
+}
+
+#[test]
+fn every_day_but_12_has_a_generator() {
+    let days = generators().map(|puzzle| puzzle.day()).collect::<Vec<_>>();
+    assert_eq!(days, (1..=11).collect::<Vec<_>>());
+}
+
+#[test]
+fn generated_inputs_solve() {
+    for puzzle in generators() {
+        for seed in 0..5 {
+            let input = puzzle.generate(seed, 30).unwrap();
+            let parsed = puzzle
+                .parse_input(&input)
+                .unwrap_or_else(|err| panic!("day {} seed {}: {}\n{}", puzzle.day(), seed, err, input));
+
+            for part in 1..=puzzle.parts() {
+                puzzle
+                    .solve_part(parsed.as_ref(), part)
+                    .unwrap_or_else(|err| panic!("day {} seed {} part {}: {}\n{}", puzzle.day(), seed, part, err, input));
+            }
+        }
+    }
+}
+
+#[test]
+fn seeds_decide_the_input() {
+    for puzzle in generators() {
+        assert_eq!(puzzle.generate(7, 30), puzzle.generate(7, 30), "day {}", puzzle.day());
+        assert_ne!(puzzle.generate(7, 30), puzzle.generate(8, 30), "day {}", puzzle.day());
+    }
+}
@@ -0,0 +1,113 @@
+//! The days' domain types can be used directly, without going through
+//! `Solution`, and agree with the answers the runner gives.
+
+use aoc_core::{Grid, Vec2, Vec3};
+
+#[test]
+fn day01_dial_counts_zeros() {
+    let dial: aoc2025_day01::Dial = "L68\nL30\nR48\nL5\nR60\nL55\nL1\nL99\nR14\nL82".parse().unwrap();
+    assert_eq!((dial.stops_at_zero(), dial.clicks_at_zero(), dial.position()), (3, 6, 32));
+}
+
+#[test]
+fn day02_ranges_list_invalid_ids() {
+    let range = aoc2025_day02::Range::new(95, 115);
+    assert_eq!(range.find_invalid_ids_p1(), Some(vec![99]));
+
+    let mut ids = range.find_invalid_ids_p2().unwrap();
+    ids.sort();
+    assert_eq!(ids, vec![99, 111]);
+
+    let everything = aoc2025_day02::Range::new(1, u64::MAX);
+    assert_eq!((everything.find_invalid_ids_p1(), everything.find_invalid_ids_p2()), (None, None));
+    assert!(everything.sum_invalid_ids_p2() > everything.sum_invalid_ids_p1());
+}
+
+#[test]
+fn day03_banks_pick_batteries() {
+    let bank: aoc2025_day03::BatteryBank = "818181911112111".parse().unwrap();
+    assert_eq!((bank.best2(), bank.best12()), (92, 888911112111));
+}
+
+#[test]
+fn day04_floors_can_be_built_from_grids() {
+    let rolls = Grid::parse("@@@\n@@@\n@@@", "`@`", |ch| Some(ch == '@')).unwrap();
+    let mut floor = aoc2025_day04::Floor::new(rolls);
+
+    assert!(floor.is_forkliftable(Vec2::new(0, 0)));
+    assert!(!floor.is_forkliftable(Vec2::new(1, 1)));
+    assert_eq!(floor.count_forkliftable(), 4);
+    assert_eq!(floor.remove_forkliftables(), 9);
+}
+
+#[test]
+fn day05_databases_merge_ranges() {
+    let db = aoc2025_day05::IngredientDatabase::new(vec![(3, 5), (10, 14), (16, 20), (12, 18)], vec![1, 5, 8, 11, 17, 32]);
+    assert_eq!((db.count_fresh_ingredients(), db.count_all_fresh_ingredients()), (3, 14));
+}
+
+#[test]
+fn day06_problems_compute() {
+    let problem = aoc2025_day06::Problem { nums: vec![4, 431, 623], operator: aoc2025_day06::Operator::Add };
+    assert_eq!(problem.compute(), Some(1058));
+}
+
+#[test]
+fn day07_manifolds_split_beams() {
+    let manifold: aoc2025_day07::Manifold = "..S..\n.....\n..^..\n.....\n.^.^.\n.....".parse().unwrap();
+
+    assert_eq!(manifold.start(), Vec2::new(2, 0));
+    assert_eq!(manifold.tiles()[Vec2::new(2, 2)], aoc2025_day07::Tile::Splitter);
+    assert_eq!(manifold.count_splits(), 3);
+}
+
+#[test]
+fn day08_boxes_join_into_circuits() {
+    let boxes = ["0,0,0", "1,0,0", "10,0,0", "11,0,0", "30,0,0"]
+        .iter()
+        .map(|line| aoc2025_day08::get_box(line).unwrap())
+        .collect::<Vec<_>>();
+
+    assert_eq!(boxes[1], Vec3::new(1, 0, 0));
+    assert_eq!(aoc2025_day08::get_circuits_p1(2, &boxes).len(), 3);
+
+    let example = std::fs::read_to_string(aoc::day_dir(2025, 8).join("test.txt")).unwrap();
+    let boxes = example.lines().map(|line| aoc2025_day08::get_box(line).unwrap()).collect::<Vec<_>>();
+    assert_eq!(aoc2025_day08::largest_circuits_product(10, &boxes), 40);
+}
+
+#[test]
+fn day09_polygons_find_rectangles() {
+    assert_eq!(aoc2025_day09::parse_point("4,1").unwrap(), Vec2::new(4, 1));
+    let polygon: aoc2025_day09::Polygon = "1,1\n4,1\n4,3\n1,3".parse().unwrap();
+    assert!("1,1\n4,1".parse::<aoc2025_day09::Polygon>().is_err());
+    assert!("1,1\n4,1\n4,3".parse::<aoc2025_day09::Polygon>().is_err());
+
+    assert_eq!(polygon.largest_area(), 12);
+    assert_eq!(polygon.largest_valid_rect().2, 12);
+}
+
+#[test]
+fn day10_machines_find_fewest_presses() {
+    let machine: aoc2025_day10::Machine = "[.##.] (3) (1,3) (2) (2,3) (0,2) (0,1) {3,5,4,7}".parse().unwrap();
+    assert_eq!(machine.buttons().len(), 6);
+    assert_eq!(machine.minimum_presses_p1(), Ok(2));
+    assert_eq!(machine.minimum_presses_p2(), Ok(10));
+
+    let stuck: aoc2025_day10::Machine = "[.#] (0) {1,1}".parse().unwrap();
+    assert_eq!(stuck.minimum_presses_p2(), Err(aoc2025_day10::SearchError::Unreachable));
+}
+
+#[test]
+fn day11_paths_are_counted_from_any_device() {
+    let nodes = ["you: a b", "a: out", "b: a out"]
+        .iter()
+        .map(|line| line.parse::<aoc2025_day11::Node>().unwrap())
+        .collect::<Vec<_>>();
+    let index = aoc2025_day11::index_nodes(&nodes);
+
+    assert_eq!(aoc2025_day11::count_paths(&index), Some(3));
+    assert_eq!(aoc2025_day11::count_paths_from(&index, "b"), Some(2));
+    assert_eq!(aoc2025_day11::count_paths_between(&index, "you", "a"), Some(2));
+    assert_eq!(aoc2025_day11::count_paths_between(&index, "a", "b"), Some(0));
+}
@@ -0,0 +1,22 @@
+//! Scans every ID in every range.
+
+fn ids(input: &str) -> impl Iterator<Item = u64> + '_ {
+    input.trim().split(',').flat_map(|range| {
+        let (start, end) = range.split_once('-').unwrap();
+        start.parse::<u64>().unwrap()..=end.parse::<u64>().unwrap()
+    })
+}
+
+/// Whether `id` is some run of digits repeated `times` times.
+fn repeats(id: u64, times: usize) -> bool {
+    let digits = id.to_string();
+    digits.len().is_multiple_of(times) && digits[..digits.len() / times].repeat(times) == digits
+}
+
+pub fn part1(input: &str) -> u64 {
+    ids(input).filter(|&id| repeats(id, 2)).sum()
+}
+
+pub fn part2(input: &str) -> u64 {
+    ids(input).filter(|&id| (2..=id.to_string().len()).any(|times| repeats(id, times))).sum()
+}
@@ -0,0 +1,24 @@
+//! Tries every way of keeping a bank's batteries in order.
+
+/// The largest number made of `count` of `bank`'s digits, in order.
+fn best(bank: &str, count: usize) -> u64 {
+    let digits = bank.bytes().map(|byte| (byte - b'0') as u64).collect::<Vec<_>>();
+
+    (0u32..1 << digits.len())
+        .filter(|mask| mask.count_ones() as usize == count)
+        .map(|mask| {
+            (0..digits.len())
+                .filter(|i| mask & (1 << i) != 0)
+                .fold(0, |number, i| number * 10 + digits[i])
+        })
+        .max()
+        .unwrap()
+}
+
+pub fn part1(input: &str) -> u64 {
+    input.lines().map(|bank| best(bank, 2)).sum()
+}
+
+pub fn part2(input: &str) -> u64 {
+    input.lines().map(|bank| best(bank, 12)).sum()
+}
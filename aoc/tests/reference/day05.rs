@@ -0,0 +1,27 @@
+//! Lists every fresh ID in a set.
+
+use std::collections::BTreeSet;
+
+fn database(input: &str) -> (BTreeSet<u64>, Vec<u64>) {
+    let (ranges, ingredients) = input.split_once("\n\n").unwrap();
+
+    let fresh = ranges
+        .lines()
+        .flat_map(|range| {
+            let (start, end) = range.split_once('-').unwrap();
+            start.parse::<u64>().unwrap()..=end.parse::<u64>().unwrap()
+        })
+        .collect();
+    let ingredients = ingredients.lines().map(|id| id.parse().unwrap()).collect();
+
+    (fresh, ingredients)
+}
+
+pub fn part1(input: &str) -> usize {
+    let (fresh, ingredients) = database(input);
+    ingredients.iter().filter(|id| fresh.contains(id)).count()
+}
+
+pub fn part2(input: &str) -> usize {
+    database(input).0.len()
+}
@@ -0,0 +1,71 @@
+//! Colors in every tile of the floor and checks rectangles tile by tile.
+
+use std::collections::HashSet;
+
+fn red_tiles(input: &str) -> Vec<(i64, i64)> {
+    input
+        .lines()
+        .map(|line| {
+            let (x, y) = line.split_once(',').unwrap();
+            (x.parse().unwrap(), y.parse().unwrap())
+        })
+        .collect()
+}
+
+fn area((x1, y1): (i64, i64), (x2, y2): (i64, i64)) -> i64 {
+    ((x1 - x2).abs() + 1) * ((y1 - y2).abs() + 1)
+}
+
+/// Every red or green tile: the loop through the red tiles and everything
+/// it encloses.
+fn colored(red: &[(i64, i64)]) -> HashSet<(i64, i64)> {
+    let edges = red.iter().zip(red.iter().cycle().skip(1)).collect::<Vec<_>>();
+
+    let mut tiles = HashSet::new();
+    for (&(x1, y1), &(x2, y2)) in &edges {
+        for x in x1.min(x2)..=x1.max(x2) {
+            for y in y1.min(y2)..=y1.max(y2) {
+                tiles.insert((x, y));
+            }
+        }
+    }
+
+    let (max_x, max_y) = (red.iter().map(|p| p.0).max().unwrap(), red.iter().map(|p| p.1).max().unwrap());
+    for x in 0..=max_x {
+        for y in 0..=max_y {
+            // A ray to the right crosses the loop an odd number of times from
+            // inside; counting vertical edges half-open in y counts each
+            // crossing once.
+            let crossings = edges
+                .iter()
+                .filter(|(&(x1, y1), &(x2, y2))| x1 == x2 && x1 > x && y1.min(y2) <= y && y < y1.max(y2))
+                .count();
+            if crossings % 2 == 1 {
+                tiles.insert((x, y));
+            }
+        }
+    }
+
+    tiles
+}
+
+pub fn part1(input: &str) -> i64 {
+    let red = red_tiles(input);
+    red.iter().flat_map(|&a| red.iter().map(move |&b| area(a, b))).max().unwrap()
+}
+
+pub fn part2(input: &str) -> i64 {
+    let red = red_tiles(input);
+    let colored = colored(&red);
+
+    let inside = |(x1, y1): (i64, i64), (x2, y2): (i64, i64)| {
+        (x1.min(x2)..=x1.max(x2)).all(|x| (y1.min(y2)..=y1.max(y2)).all(|y| colored.contains(&(x, y))))
+    };
+
+    red.iter()
+        .flat_map(|&a| red.iter().map(move |&b| (a, b)))
+        .filter(|&(a, b)| inside(a, b))
+        .map(|(a, b)| area(a, b))
+        .max()
+        .unwrap()
+}
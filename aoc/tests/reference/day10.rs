@@ -0,0 +1,41 @@
+//! Searches breadth-first through every joltage reachable one press at a
+//! time.
+
+use std::collections::{HashSet, VecDeque};
+
+fn fewest_presses(buttons: &[Vec<usize>], joltages: &[u64]) -> u64 {
+    let start = vec![0; joltages.len()];
+    let mut seen = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+
+    while let Some((reached, presses)) = queue.pop_front() {
+        if reached == joltages {
+            return presses;
+        }
+        for button in buttons {
+            let mut next = reached.clone();
+            button.iter().for_each(|&counter| next[counter] += 1);
+            if next.iter().zip(joltages).all(|(a, b)| a <= b) && seen.insert(next.clone()) {
+                queue.push_back((next, presses + 1));
+            }
+        }
+    }
+    panic!("no presses reach {:?}", joltages);
+}
+
+pub fn part2(input: &str) -> u64 {
+    input
+        .lines()
+        .map(|line| {
+            let words = line.split(' ').collect::<Vec<_>>();
+            let numbers = |word: &str| {
+                word[1..word.len() - 1].split(',').map(|n| n.parse().unwrap()).collect::<Vec<_>>()
+            };
+            let buttons = words[1..words.len() - 1]
+                .iter()
+                .map(|word| numbers(word).into_iter().map(|n| n as usize).collect())
+                .collect::<Vec<_>>();
+            fewest_presses(&buttons, &numbers(words[words.len() - 1]))
+        })
+        .sum()
+}
@@ -0,0 +1,10 @@
+//! Naive solutions to compare the real solvers with. Each one parses the
+//! input itself and does the most obvious thing, however slow, so it shares
+//! no code or cleverness with the day it checks. They only cope with the
+//! small inputs the differential tests generate.
+
+pub mod day02;
+pub mod day03;
+pub mod day05;
+pub mod day09;
+pub mod day10;
@@ -0,0 +1,145 @@
+//! Every day must turn any text into a parse error, or an answer or error
+//! for each part, without panicking. proptest mutates the examples and
+//! makes up text from scratch; the fuzz targets in `fuzz/` go further.
+
+use aoc_core::answers::Manifest;
+use aoc_core::Puzzle;
+use proptest::prelude::*;
+
+fn days() -> &'static [&'static dyn Puzzle] {
+    aoc::days::year(2025).expect("2025 is registered").days
+}
+
+/// Parses `input` and solves every implemented part, ignoring the outcome.
+fn solve(puzzle: &dyn Puzzle, input: &str) {
+    if let Ok(parsed) = puzzle.parse_input(input) {
+        for part in 1..=puzzle.parts() {
+            let _ = puzzle.solve_part(parsed.as_ref(), part);
+        }
+    }
+}
+
+/// Each day's examples, the inputs other than the puzzle input.
+fn examples() -> Vec<(&'static dyn Puzzle, String)> {
+    days()
+        .iter()
+        .flat_map(|&puzzle| {
+            let dir = aoc::day_dir(2025, puzzle.day());
+            let manifest = Manifest::load(&dir).unwrap();
+            manifest
+                .inputs
+                .into_iter()
+                .filter(|listed| listed.file != "input.txt")
+                .map(move |listed| (puzzle, std::fs::read_to_string(dir.join(&listed.file)).unwrap()))
+        })
+        .collect()
+}
+
+/// One change to an example.
+#[derive(Debug, Clone)]
+enum Edit {
+    Delete(prop::sample::Index),
+    Insert(prop::sample::Index, char),
+    Replace(prop::sample::Index, char),
+    Truncate(prop::sample::Index),
+}
+
+impl Edit {
+    fn apply(&self, text: &mut Vec<char>) {
+        match self {
+            Edit::Delete(at) if !text.is_empty() => {
+                text.remove(at.index(text.len()));
+            }
+            Edit::Insert(at, ch) => text.insert(at.index(text.len() + 1), *ch),
+            Edit::Replace(at, ch) if !text.is_empty() => {
+                let at = at.index(text.len());
+                text[at] = *ch;
+            }
+            Edit::Truncate(at) => text.truncate(at.index(text.len() + 1)),
+            _ => {}
+        }
+    }
+}
+
+/// Mostly the characters the puzzles are written in, now and then any.
+fn character() -> impl Strategy<Value = char> {
+    prop_oneof![
+        4 => prop::sample::select("0123456789 \n,-:#.^S@+*[](){}LRyouts".chars().collect::<Vec<_>>()),
+        1 => any::<char>(),
+    ]
+}
+
+fn edit() -> impl Strategy<Value = Edit> {
+    let at = any::<prop::sample::Index>;
+    prop_oneof![
+        4 => at().prop_map(Edit::Delete),
+        4 => (at(), character()).prop_map(|(at, ch)| Edit::Insert(at, ch)),
+        4 => (at(), character()).prop_map(|(at, ch)| Edit::Replace(at, ch)),
+        1 => at().prop_map(Edit::Truncate),
+    ]
+}
+
+proptest! {
+    #![proptest_config(ProptestConfig::with_cases(500))]
+
+    #[test]
+    fn mutated_examples_never_panic(
+        example in any::<prop::sample::Index>(),
+        edits in prop::collection::vec(edit(), 1..8),
+    ) {
+        let examples = examples();
+        let (puzzle, text) = &examples[example.index(examples.len())];
+        let mut text = text.chars().collect::<Vec<_>>();
+        edits.iter().for_each(|edit| edit.apply(&mut text));
+
+        solve(*puzzle, &text.into_iter().collect::<String>());
+    }
+
+    #[test]
+    fn made_up_text_never_panics(
+        day in any::<prop::sample::Index>(),
+        text in prop::collection::vec(character(), 0..60).prop_map(String::from_iter),
+    ) {
+        solve(days()[day.index(days().len())], &text);
+    }
+}
+
+/// Inputs at the edges of what each day accepts: cut short, out of range,
+/// overflowing, wired in loops, or wired very deep.
+#[test]
+fn edge_cases_never_panic() {
+    let cases: &[(u8, &str)] = &[
+        (1, "L"),
+        (1, "R99999999999\n"),
+        (2, "1-99999999999999999999"),
+        (3, "1\n"),
+        (5, "5-3\n\n4\n"),
+        (6, "1\n"),
+        (6, "12 3\n4\n+ *\n"),
+        (6, "99999999999 99999999999\n99999999999 99999999999\n* *\n"),
+        (7, "S\n^\n"),
+        (7, ".S.\n.^^\n...\n"),
+        (8, "1,1,1\n1,1,1\n"),
+        (8, "1,2,3\n"),
+        (9, "1,1\n"),
+        (9, "1,1\n2,2\n3,3\n4,4\n"),
+        (10, "[] {}"),
+        (10, "[#] (0,0) {1}"),
+        (10, "[.#] (0) {1,1}"),
+        (11, "you: aaa\n"),
+        (11, "you: out\nyou: out\n"),
+        (11, "you: aaa\naaa: you\n"),
+    ];
+
+    for &(day, input) in cases {
+        solve(aoc::days::find(2025, day).unwrap(), input);
+    }
+
+    // Deeper than any call stack goes, one frame per device
+    let mut chain = (0..200_000).map(|i| format!("d{}: d{}", i, i + 1)).collect::<Vec<_>>();
+    chain.extend(["you: d0", "svr: d0", "d200000: dac", "dac: fft", "fft: out"].map(String::from));
+    let day11 = aoc::days::find(2025, 11).unwrap();
+    let parsed = day11.parse_input(&chain.join("\n")).unwrap();
+    assert_eq!(day11.solve_part(parsed.as_ref(), 1).unwrap(), "1");
+    assert_eq!(day11.solve_part(parsed.as_ref(), 2).unwrap(), "1");
+}
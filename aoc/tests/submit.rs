@@ -0,0 +1,88 @@
+mod common;
+
+use std::path::Path;
+use std::time::Duration;
+
+use aoc::client::{Client, ClientError, Throttle};
+use aoc::submit::{History, Refusal, Verdict};
+use common::MockServer;
+
+fn client(server: &MockServer, state: &Path) -> Client {
+    Client {
+        base_url: server.url.clone(),
+        session: Some("secret".to_string()),
+        year: 2025,
+        throttle: Throttle {
+            min_interval: Duration::ZERO,
+            stamp: state.join("last-request"),
+        },
+    }
+}
+
+/// Judges answers like the site would for a puzzle whose answer is 42.
+fn judge(request: &common::Request) -> (u16, String) {
+    let answer = request
+        .body
+        .split('&')
+        .find_map(|field| field.strip_prefix("answer="))
+        .unwrap_or_default();
+
+    let text = match answer.parse::<i64>() {
+        Ok(42) => "That's the right answer! You are one gold star closer.",
+        Ok(n) if n > 42 => "That's not the right answer; your answer is too high.",
+        Ok(_) => "That's not the right answer; your answer is too low.",
+        Err(_) => "That's not the right answer.",
+    };
+    (200, format!("<html><main><article><p>{}</p></article></main></html>", text))
+}
+
+#[test]
+fn posts_the_answer_and_reads_the_verdict() {
+    let server = MockServer::start(judge);
+    let dir = tempfile::tempdir().unwrap();
+
+    let verdict = client(&server, dir.path()).submit(7, 2, "42").unwrap();
+
+    assert_eq!(verdict, Verdict::Correct);
+    let requests = server.requests();
+    assert_eq!(requests[0].method, "POST");
+    assert_eq!(requests[0].path, "/2025/day/7/answer");
+    assert_eq!(requests[0].body, "level=2&answer=42");
+    assert_eq!(requests[0].header("Cookie"), Some("session=secret"));
+}
+
+#[test]
+fn pages_without_a_verdict_are_errors() {
+    let server = MockServer::start(|_| (200, "<html>Puzzle inputs differ by user.</html>".to_string()));
+    let dir = tempfile::tempdir().unwrap();
+
+    let err = client(&server, dir.path()).submit(1, 1, "5").unwrap_err();
+
+    assert!(matches!(err, ClientError::NoVerdict { .. }), "{}", err);
+}
+
+#[test]
+fn history_narrows_down_the_answer() {
+    let server = MockServer::start(judge);
+    let dir = tempfile::tempdir().unwrap();
+    let client = client(&server, dir.path());
+
+    for answer in ["100", "10"] {
+        let mut history = History::load(dir.path()).unwrap();
+        history.check(1, answer).unwrap();
+        let verdict = client.submit(1, 1, answer).unwrap();
+        history.record(1, answer, verdict);
+        history.save(dir.path()).unwrap();
+    }
+
+    let history = History::load(dir.path()).unwrap();
+    let verdicts = history.attempts.iter().map(|attempt| attempt.verdict).collect::<Vec<_>>();
+    assert_eq!(verdicts, vec![Verdict::TooHigh, Verdict::TooLow]);
+
+    assert_eq!(history.check(1, "100"), Err(Refusal::KnownWrong(Verdict::TooHigh)));
+    assert_eq!(history.check(1, "150"), Err(Refusal::AtLeast("100".to_string())));
+    assert_eq!(history.check(1, "3"), Err(Refusal::AtMost("10".to_string())));
+    assert_eq!(history.check(1, "42"), Ok(()));
+    assert_eq!(history.check(2, "100"), Ok(()));
+    assert_eq!(server.requests().len(), 2);
+}
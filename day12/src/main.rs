@@ -1,4 +0,0 @@
-fn main() {
-    println!("Advent of Code 2025 - Day 12");
-    // Your solution here
-}
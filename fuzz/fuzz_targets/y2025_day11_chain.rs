@@ -0,0 +1,29 @@
+//! Day 11 wiring far deeper than the byte inputs of the `y2025_day11`
+//! target reach: a long chain of devices, with extra wires chosen by the
+//! fuzzer running forwards, or backwards into a loop.
+
+#![no_main]
+
+use libfuzzer_sys::fuzz_target;
+
+fuzz_target!(|data: &[u8]| {
+    let Some((&[a, b, c], wires)) = data.split_first_chunk::<3>() else { return };
+    let length = u32::from_le_bytes([a, b, c, 0]) as usize % 300_000 + 1;
+
+    let mut outputs = (0..length).map(|i| vec![format!("d{}", i + 1)]).collect::<Vec<_>>();
+    for wire in wires.chunks_exact(6) {
+        let from = u32::from_le_bytes([wire[0], wire[1], wire[2], 0]) as usize % length;
+        let to = u32::from_le_bytes([wire[3], wire[4], wire[5], 0]) as usize % (length + 1);
+        outputs[from].push(format!("d{}", to));
+    }
+
+    let mut lines = outputs
+        .iter()
+        .enumerate()
+        .map(|(i, outputs)| format!("d{}: {}", i, outputs.join(" ")))
+        .collect::<Vec<_>>();
+    lines.push(format!("d{}: out", length));
+    lines.extend(["you: d0", "svr: d0"].map(String::from));
+
+    aoc_fuzz::solve(&aoc2025_day11::Day11, lines.join("\n").as_bytes());
+});
@@ -0,0 +1,16 @@
+//! Shared by the fuzz targets, one per day, each holding its day to the
+//! guarantee on [`Solution`]: any text parses or not and then gets an
+//! answer or an error for each part, without panicking.
+
+use aoc_core::{Puzzle, Solution};
+
+/// Parses `data`, if it is UTF-8, and solves every implemented part.
+pub fn solve<S: Solution + Sync>(puzzle: &S, data: &[u8]) {
+    let Ok(input) = std::str::from_utf8(data) else { return };
+
+    if let Ok(parsed) = puzzle.parse_input(input) {
+        for part in 1..=puzzle.parts() {
+            let _ = puzzle.solve_part(parsed.as_ref(), part);
+        }
+    }
+}